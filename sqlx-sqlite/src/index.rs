@@ -1,27 +1,43 @@
-use std::{collections::HashMap, fmt::Display, fs::File, path::Path, sync::Arc};
+use std::{collections::HashMap, fmt::Display, path::Path, sync::Arc};
 
-use datafusion::arrow::array::AsArray;
+use datafusion::arrow::array::{Array, AsArray};
 use datafusion::arrow::datatypes::{
-    DataType, Int16Type, Int32Type, Int64Type, Int8Type, SchemaRef, UInt16Type, UInt32Type, UInt64Type, UInt8Type
+    DataType, Date32Type, Date64Type, Decimal128Type, Fields, Float32Type, Float64Type, Int16Type, Int32Type,
+    Int64Type, Int8Type, SchemaRef, Time32MillisecondType, Time32SecondType, Time64MicrosecondType,
+    Time64NanosecondType, TimeUnit, TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
 };
 use datafusion::physical_optimizer::pruning::PruningPredicate;
 use datafusion::{
     datasource::physical_plan::parquet::{ParquetAccessPlan, RowGroupAccess, StatisticsConverter},
-    parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder,
+    parquet::arrow::{
+        arrow_reader::{ArrowReaderOptions, RowSelection, RowSelector},
+        async_reader::{ParquetObjectReader, ParquetRecordBatchStreamBuilder},
+        ProjectionMask,
+    },
+    parquet::bloom_filter::Sbbf,
 };
+use bytes::Buf;
+use futures::TryStreamExt;
+use object_store::{local::LocalFileSystem, ObjectStore};
 use datafusion_common::tree_node::TreeNode;
-use datafusion_common::{internal_datafusion_err, DataFusionError, Result, tree_node::{Transformed, TransformedResult}};
+use datafusion_common::{
+    internal_datafusion_err, ColumnStatistics as DFColumnStatistics, DataFusionError, Precision, Result, ScalarValue,
+    Statistics, tree_node::{Transformed, TransformedResult},
+};
+use datafusion_expr::Operator;
 use datafusion_physical_expr::PhysicalExpr;
 use sea_query::{
-    Alias, ColumnDef, CommonTableExpression, Expr as SeaQExpr, ForeignKey, ForeignKeyAction, Index, OnConflict, Query, SimpleExpr, SqliteQueryBuilder, Table, WithClause
+    Alias, ColumnDef, CommonTableExpression, Expr as SeaQExpr, ForeignKey, ForeignKeyAction, Index, MysqlQueryBuilder,
+    OnConflict, PostgresQueryBuilder, Query, SchemaStatementBuilder, SimpleExpr, SqliteQueryBuilder, Table, WithClause,
 };
-use sea_query_binder::SqlxBinder;
-use sqlx::SqlitePool;
+use sea_query_binder::{SqlxBinder, SqlxValues};
+use sqlx::any::AnyPool;
 use datafusion_physical_expr::expressions as phys_expr;
 
 use crate::rewrite::physical_expr_to_sea_query;
 
-/// SQLite secondary index for a set of parquet files
+/// SQL secondary index for a set of parquet files
 ///
 /// It stores file-level data (filename and file size) as well as statistics for each column
 /// in each row group of each file.
@@ -36,10 +52,25 @@ use crate::rewrite::physical_expr_to_sea_query;
 /// (file_name, row_group, row_number) and use that to enable fast point lookups on parquet files.
 /// This is not implemented in this example.
 ///
-/// The index is implemented as a SQLite database with two tables:
+/// We *do* implement one level of finer grained filtering: the Parquet page index. `add_file`
+/// stores per-page min/max/null-count statistics (when the file has a page index) in
+/// `page_statistics`, keyed by the contiguous row range `[first_row_index, first_row_index +
+/// row_count)` that each data page covers. `get_files` applies the same pruning predicate to
+/// `page_statistics` for row groups that survive the row-group-level pass, and turns the pages
+/// that don't qualify into a `RowSelection` so only the matching rows are decoded, rather than
+/// the whole row group.
+///
+/// The index is implemented as a relational database (SQLite, Postgres, or MySQL - see
+/// [`Backend`]) with five tables:
 /// - `file_statistics` with columns `file_id`, `file_name`, `file_size_bytes`, `row_group_count`, `row_count`
 /// - `column_statistics` with columns `file_id`, `column_name`, `row_group`, `null_count`, `row_count`,
 ///    and min/max values for each data type we support
+/// - `page_statistics` with columns `file_id`, `row_group`, `column_name`, `page_index`,
+///    `first_row_index`, `row_count`, `null_count`, and generic min/max value columns
+/// - `row_group_blooms`, optionally, with columns `file_id`, `row_group`, `column_name`,
+///    `bloom_filter`, for columns passed to [`SqlIndex::with_bloom_filter_columns`]
+/// - `file_partitions` with columns `file_id`, `partition_column`, and typed value columns, for
+///    tables declared via [`SqlIndex::with_partition_schema`]
 ///
 /// Here is roughly what `SELECT * FROM file_statistics` would look like:
 /// | file_id | file_name     | file_size_bytes | row_group_count | row_count |
@@ -77,25 +108,114 @@ use crate::rewrite::physical_expr_to_sea_query;
 /// WHERE column1_min <= 10 AND column1_max >= 10 AND column2_min <= 'b' AND column2_max >= 'b'
 /// ```
 ///
+/// This index was originally SQLite-only; it now runs against any of the three dialects sea-query
+/// can generate DDL/DML for, so a cluster of query nodes can point at one shared Postgres or MySQL
+/// catalog instead of each maintaining its own local SQLite file. `pool` is therefore a
+/// driver-erased [`AnyPool`] rather than `SqlitePool`, and every place that used to build SQL with
+/// a hardcoded `SqliteQueryBuilder` now goes through [`SqlIndex::build_sqlx`] /
+/// [`SqlIndex::build_schema`], which pick the matching sea-query builder for `self.backend`.
+///
+/// One known gap: `column_statistics` embeds raw SQL via `SeaQExpr::cust("CAST(x AS TEXT)")`, and
+/// `TEXT` isn't a MySQL cast target (`CHAR` is) - that will need adjusting before this index can
+/// actually run against a MySQL backend.
+///
+/// `Struct` columns are flattened into one indexed leaf per primitive field, recursively, using a
+/// dotted path (`address.zip`) to look the leaf up in the Parquet file and an underscore-joined
+/// SQL name (`address_zip`) for its `_min`/`_max`/`_null_count`/`_distinct_count` columns - see
+/// [`leaf_columns`]. `List`/`Map` columns are left out of the index entirely, same as any other
+/// unsupported type.
+///
+/// A table laid out with Hive-style partitioning (e.g. `year=2024/region=us/part-0.parquet`) can
+/// declare its partition columns via [`SqlIndex::with_partition_schema`]. [`SqlIndex::get_files`]
+/// then prunes in two phases: first whole files are eliminated by evaluating the filter against
+/// `file_partitions`, then the usual row-group/page-level min/max pruning runs only over the
+/// survivors - cutting I/O before `row_group_statistics` is even consulted. A partition column
+/// with no stored value for a given file (or one referenced by the filter but absent from
+/// `partition_schema`) simply isn't used for phase 1, and that file falls through to phase 2
+/// unrestricted.
+///
 /// While we use SQLite in this example, the index could be implemented with other databases or system.
 /// SQLite is just a convenient example that is also very similar to other RDBMS systems that you might use.
 #[derive(Debug)]
-pub struct SQLiteIndex {
-    pool: SqlitePool,
+pub struct SqlIndex {
+    pool: AnyPool,
+    backend: Backend,
     /// The index for the schema. Not all columns in the table need to be indexed.
     schema: SchemaRef,
+    /// Columns that additionally get a per-row-group Split Block Bloom Filter, for fast point
+    /// lookups on high-cardinality columns where min/max pruning rarely helps (see
+    /// [`Self::with_bloom_filter_columns`]).
+    bloom_columns: std::collections::HashSet<String>,
+    /// The Hive partition columns this table is laid out by (e.g. `year=2024/region=us` in the
+    /// object store path), if any - see [`Self::with_partition_schema`]. `None` means this table
+    /// isn't partitioned and `file_partitions` is left empty.
+    partition_schema: Option<SchemaRef>,
 }
 
-impl Display for SQLiteIndex {
+impl Display for SqlIndex {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "SQLiteIndex()")?;
+        writeln!(f, "SqlIndex({:?})", self.backend)?;
         Ok(())
     }
 }
 
-impl SQLiteIndex {
-    pub fn new(pool: SqlitePool, schema: SchemaRef) -> Self {
-        Self { pool, schema }
+/// Which SQL dialect (and therefore which sea-query builder) a [`SqlIndex`] generates its
+/// DDL/DML for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    Sqlite,
+    Postgres,
+    MySql,
+}
+
+impl SqlIndex {
+    pub fn new(pool: AnyPool, backend: Backend, schema: SchemaRef) -> Self {
+        Self {
+            pool,
+            backend,
+            schema,
+            bloom_columns: std::collections::HashSet::new(),
+            partition_schema: None,
+        }
+    }
+
+    /// Declare that files added to this index live under a Hive-style partitioned layout (e.g.
+    /// `year=2024/region=us/data.parquet`), with the partition columns and their types given by
+    /// `partition_schema` - the same schema you'd hand to a `ListingTable`'s partition columns.
+    /// `add_file`/`add_file_from_store` parse the `key=value` path segments matching these columns
+    /// into `file_partitions`, and `get_files` prunes whole files against them before even looking
+    /// at `row_group_statistics`.
+    pub fn with_partition_schema(mut self, partition_schema: SchemaRef) -> Self {
+        self.partition_schema = Some(partition_schema);
+        self
+    }
+
+    /// Build a query statement's SQL/bind values using whichever sea-query builder matches
+    /// `self.backend`, instead of the hardcoded `SqliteQueryBuilder` this index used to require.
+    fn build_sqlx<T: SqlxBinder>(&self, statement: &T) -> (String, SqlxValues) {
+        match self.backend {
+            Backend::Sqlite => statement.build_sqlx(SqliteQueryBuilder),
+            Backend::Postgres => statement.build_sqlx(PostgresQueryBuilder),
+            Backend::MySql => statement.build_sqlx(MysqlQueryBuilder),
+        }
+    }
+
+    /// Same as [`Self::build_sqlx`] but for schema (DDL) statements, which sea-query builds as a
+    /// plain `String` with no bind values.
+    fn build_schema<T: SchemaStatementBuilder>(&self, statement: &T) -> String {
+        match self.backend {
+            Backend::Sqlite => statement.build(SqliteQueryBuilder),
+            Backend::Postgres => statement.build(PostgresQueryBuilder),
+            Backend::MySql => statement.build(MysqlQueryBuilder),
+        }
+    }
+
+    /// Additionally build a Split Block Bloom Filter for the given columns, one per row group.
+    /// `get_files` uses these to prune row groups on equality predicates (`col = literal`) that
+    /// min/max pruning can't help with, e.g. a high-cardinality `id` column.
+    pub fn with_bloom_filter_columns(mut self, columns: impl IntoIterator<Item = String>) -> Self {
+        self.bloom_columns.extend(columns);
+        self
     }
 
     /// Return the filenames / row groups that match the filter
@@ -113,6 +233,16 @@ impl SQLiteIndex {
         filter: Arc<dyn PhysicalExpr>,
         schema: SchemaRef,
     ) -> Result<Vec<(String, FileScanPlan)>> {
+        // Pulled from the raw filter before `PruningPredicate` rewrites it into min/max bound
+        // comparisons, since we need the literal being compared against, not a bound on it.
+        let bloom_equalities = bloom_equality_predicates(&filter, &self.bloom_columns);
+
+        // Phase 1: if this table is Hive-partitioned, eliminate whole files using the partition
+        // columns the filter references, before even consulting `row_group_statistics`. A
+        // partition column is a single value per file rather than a range, so the raw filter is
+        // evaluated directly instead of going through `PruningPredicate`'s min/max bound rewrite.
+        let surviving_files = self.files_surviving_partition_pruning(&filter).await?;
+
         // Convert the predicate to a pruning predicate
         // This transforms e.g. `a = 5` to `a_min <= 5 AND a_max >= 5`
         let pruning = PruningPredicate::try_new(filter, schema.clone())?;
@@ -127,17 +257,41 @@ impl SQLiteIndex {
             }
             Ok(Transformed::no(expr))
         }).data()?;
+
+        // Columns the predicate actually touches, so we know which per-column CTEs we need
+        // when we come to refine a row group using `page_statistics` below.
+        let referenced_columns = referenced_min_max_columns(&predicate);
+
         // Convert a DataFusion PhysicalExpr to a SeaQuery SimpleExpr
         let predicate = physical_expr_to_sea_query(&predicate);
 
-        let stats_query = Query::select()
+        // A referenced column's min/max can be NULL (no usable Parquet stats for that row
+        // group), in which case SQL's three-valued logic would make `predicate` evaluate to
+        // NULL rather than TRUE or FALSE - and a plain `WHERE predicate` silently drops that
+        // row. OR in an explicit NULL check per referenced column so those row groups are kept
+        // (i.e. "cannot prune, must scan") instead of being pruned by accident.
+        let predicate = referenced_columns.iter().fold(predicate, |predicate, column| {
+            let absent = SeaQExpr::col(Alias::new(format!("{column}_min")))
+                .is_null()
+                .or(SeaQExpr::col(Alias::new(format!("{column}_max"))).is_null());
+            predicate.or(absent)
+        });
+
+        let mut stats_query = Query::select()
             .from(Alias::new("row_group_statistics"))
             .columns(vec![
                 Alias::new("file_id"),
                 Alias::new("row_group"),
             ])
-            .and_where(predicate).to_owned();
-        
+            .expr_as(SeaQExpr::col(Alias::new("row_count")), Alias::new("rg_row_count"))
+            .and_where(predicate.clone()).to_owned();
+        // Phase 2 only needs to run over files that survived phase 1's partition pruning.
+        if let Some(surviving_files) = &surviving_files {
+            stats_query = stats_query
+                .and_where(SeaQExpr::col(Alias::new("file_id")).is_in(surviving_files.iter().copied()))
+                .to_owned();
+        }
+
         let cte = CommonTableExpression::new()
             .query(stats_query)
             .table_name(Alias::new("row_groups")).to_owned();
@@ -150,31 +304,45 @@ impl SQLiteIndex {
                 Alias::new("row_group_count"),
             ])
             .inner_join(
-                Alias::new("row_groups"), 
+                Alias::new("row_groups"),
                 SeaQExpr::col((Alias::new("file_statistics"), Alias::new("file_id"))).equals((Alias::new("row_groups"), Alias::new("file_id"))),
             )
+            .column((Alias::new("row_groups"), Alias::new("file_id")))
             .column(Alias::new("row_group"))
+            .column(Alias::new("rg_row_count"))
             .distinct()
             .to_owned();
 
         let query = files_query.with(WithClause::new().cte(cte).to_owned());
 
-        let (sql, values) = query.build_sqlx(SqliteQueryBuilder);
+        let (sql, values) = self.build_sqlx(&query);
 
-        let row_groups: Vec<(String, i64, i64, i64)> = sqlx::query_as_with(&sql, values)
+        let row_groups: Vec<(String, i64, i64, i64, i64, i64)> = sqlx::query_as_with(&sql, values)
             .fetch_all(&self.pool)
             .await
             .unwrap(); // TODO: handle error, possibly failing gracefully by scanning all files?
 
         let mut file_scans: HashMap<String, (i64, ParquetAccessPlan)> = HashMap::new(); // file_name -> (file_size, row_groups)
 
-        for (file_name, file_size, file_row_group_counts, row_group_to_scan) in row_groups {
+        for (file_name, file_size, file_row_group_counts, file_id, row_group_to_scan, rg_row_count) in row_groups {
+            // Not setting an entry in `access_plan` leaves it at `RowGroupAccess::Skip` (the
+            // default from `ParquetAccessPlan::new_none`), so a row group that a bloom filter
+            // rules out for any one equality predicate it covers is dropped just by `continue`ing.
+            if !self.bloom_survives(file_id, row_group_to_scan, &bloom_equalities).await? {
+                continue;
+            }
+
             let (_, access_plan) = file_scans.entry(file_name).or_insert((
                 file_size,
                 ParquetAccessPlan::new_none(file_row_group_counts as usize),
             ));
-            // Here we could do finer grained row-level filtering, but this example does not implement that
-            access_plan.set(row_group_to_scan as usize, RowGroupAccess::Scan)
+
+            let access = self
+                .page_level_access(file_id, row_group_to_scan, rg_row_count, &referenced_columns, &predicate)
+                .await?
+                .unwrap_or(RowGroupAccess::Scan);
+
+            access_plan.set(row_group_to_scan as usize, access);
         }
 
         Ok(file_scans
@@ -191,25 +359,462 @@ impl SQLiteIndex {
             .collect())
     }
 
-    /// Add a new file to the index
+    /// Phase 1 of two-phase pruning: eliminate whole files using the Hive partition columns the
+    /// filter references, via `file_partitions`. Returns `Ok(None)` when this table has no
+    /// [`Self::with_partition_schema`] or the filter doesn't reference any partition column, in
+    /// which case the caller should fall back to scanning every file at the row-group level;
+    /// otherwise the surviving `file_id`s that phase 2 should be restricted to.
+    async fn files_surviving_partition_pruning(
+        &self,
+        filter: &Arc<dyn PhysicalExpr>,
+    ) -> Result<Option<std::collections::HashSet<i64>>> {
+        let Some(partition_schema) = &self.partition_schema else {
+            return Ok(None);
+        };
+
+        // Only hand phase 1 the conjuncts that reference partition columns exclusively. The query
+        // built below only has `file_statistics` and the `partition_i` CTEs in scope, so a filter
+        // that mixes a partition conjunct with a regular data-column conjunct (e.g. `year = 2024
+        // AND price > 100`) would otherwise emit a WHERE clause referencing `price`, a column
+        // that doesn't exist here - a guaranteed SQL error instead of `price` simply falling
+        // through to phase 2 like any other non-partition reference.
+        let mut conjuncts = Vec::new();
+        split_conjuncts(filter, &mut conjuncts);
+        let partition_conjuncts: Vec<_> = conjuncts
+            .into_iter()
+            .filter(|conjunct| {
+                referenced_columns(conjunct).iter().all(|column| {
+                    partition_schema
+                        .field_with_name(column)
+                        .is_ok_and(|field| min_max_column_kind(field.data_type()).is_some())
+                })
+            })
+            .collect();
+
+        let referenced: Vec<String> = partition_conjuncts.iter().flat_map(referenced_columns).fold(
+            Vec::new(),
+            |mut columns, column| {
+                if !columns.contains(&column) {
+                    columns.push(column);
+                }
+                columns
+            },
+        );
+        if referenced.is_empty() {
+            return Ok(None);
+        }
+
+        let partition_filter = partition_conjuncts
+            .into_iter()
+            .reduce(|left, right| Arc::new(phys_expr::BinaryExpr::new(left, Operator::And, right)))
+            .expect("referenced is non-empty, so at least one conjunct qualified");
+        let predicate = physical_expr_to_sea_query(&partition_filter);
+
+        let mut ctes = WithClause::new();
+        // Drive this from `file_statistics`, the full set of known files, rather than from the
+        // first referenced partition's CTE: a file missing a `file_partitions` row for one
+        // referenced column must still come out the other end (see the `IS NULL` fallback below),
+        // and an `inner_join`/self-as-base chain off `partition_0` would drop it before that
+        // fallback ever gets a chance to run.
+        let mut query = Query::select()
+            .column((Alias::new("file_statistics"), Alias::new("file_id")))
+            .from(Alias::new("file_statistics"))
+            .to_owned();
+
+        for (i, column) in referenced.iter().enumerate() {
+            // Every conjunct above was required to reference only columns `partition_schema`
+            // knows a `min_max_column_kind` for, so this is always `Some` - select that one typed
+            // column directly rather than `COALESCE`ing every typed column together (only the
+            // other four of which are guaranteed NULL, but whose types Postgres won't let
+            // `COALESCE` mix with this one's).
+            let field = partition_schema.field_with_name(column).expect("filtered to known partition columns above");
+            let kind = min_max_column_kind(field.data_type()).expect("filtered to indexable kinds above");
+            let cte_name = Alias::new(format!("partition_{i}"));
+            let cte_query = Query::select()
+                .from(Alias::new("file_partitions"))
+                .column(Alias::new("file_id"))
+                .expr_as(SeaQExpr::col(Alias::new(format!("{kind}_value"))), Alias::new(column.as_str()))
+                .and_where(SeaQExpr::col(Alias::new("partition_column")).eq(column.as_str()))
+                .to_owned();
+            ctes.cte(CommonTableExpression::new().query(cte_query).table_name(cte_name.clone()).to_owned());
+
+            query = query
+                .left_join(
+                    cte_name.clone(),
+                    SeaQExpr::col((Alias::new("file_statistics"), Alias::new("file_id")))
+                        .equals((cte_name.clone(), Alias::new("file_id"))),
+                )
+                .column((cte_name.clone(), Alias::new(column.as_str())))
+                .to_owned();
+        }
+
+        // A referenced partition column can be NULL here (no `file_partitions` row for this
+        // file/column), in which case the predicate can't be evaluated and SQL's three-valued
+        // logic would make it NULL rather than TRUE or FALSE. OR in an explicit NULL check per
+        // referenced column, same as `get_files`/`page_level_access`, so those files fall through
+        // to phase 2 unrestricted instead of being silently dropped by the `LEFT JOIN`.
+        let predicate = referenced.iter().enumerate().fold(predicate, |predicate, (i, column)| {
+            let absent =
+                SeaQExpr::col((Alias::new(format!("partition_{i}")), Alias::new(column.as_str()))).is_null();
+            predicate.or(absent)
+        });
+
+        let query = query.and_where(predicate).with(ctes).to_owned();
+        let (sql, values) = self.build_sqlx(&query);
+        let rows: Vec<(i64,)> = sqlx::query_as_with(&sql, values)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+
+        Ok(Some(rows.into_iter().map(|(file_id,)| file_id).collect()))
+    }
+
+    /// Refine a row group that already qualified at the row-group level by consulting
+    /// `page_statistics`. Returns `Ok(None)` when the row group has no stored page index (or no
+    /// statistics for one of the referenced columns), in which case the caller should fall back
+    /// to scanning the whole row group.
+    ///
+    /// Note this assumes data pages line up across the referenced columns by `page_index`, i.e.
+    /// that the file was written with a row-count based page size limit. That holds for files
+    /// produced by the Arrow parquet writer with its default settings, but is not a Parquet
+    /// invariant in general.
+    async fn page_level_access(
+        &self,
+        file_id: i64,
+        row_group: i64,
+        row_group_row_count: i64,
+        referenced_columns: &[String],
+        predicate: &SimpleExpr,
+    ) -> Result<Option<RowGroupAccess>> {
+        if referenced_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let mut ctes = WithClause::new();
+        let mut pages_query = Query::select()
+            .columns(vec![
+                Alias::new("page_index"),
+                Alias::new("first_row_index"),
+            ])
+            .expr_as(SeaQExpr::col(Alias::new("row_count")), Alias::new("page_row_count"))
+            .to_owned();
+
+        for (i, column) in referenced_columns.iter().enumerate() {
+            // Select the one typed column this column's Arrow type actually populates instead of
+            // `COALESCE`ing every typed column together - only valid under SQLite's dynamic
+            // typing, a hard type-mismatch error on Postgres. A column we don't know a kind for
+            // (not in `self.schema`, or an unindexed type) never has a populated typed column
+            // either way, so a bare `NULL` is equivalent and just as un-prunable.
+            let kind = self.schema.field_with_name(column).ok().and_then(|field| min_max_column_kind(field.data_type()));
+            let (min_expr, max_expr) = match kind {
+                Some(kind) => (SeaQExpr::col(Alias::new(format!("{kind}_min"))), SeaQExpr::col(Alias::new(format!("{kind}_max")))),
+                None => (SeaQExpr::cust("NULL"), SeaQExpr::cust("NULL")),
+            };
+            let cte_name = Alias::new(format!("page_{i}"));
+            let cte_query = Query::select()
+                .from(Alias::new("page_statistics"))
+                .columns(vec![
+                    Alias::new("page_index"),
+                    Alias::new("first_row_index"),
+                    Alias::new("row_count"),
+                ])
+                .expr_as(min_expr, Alias::new(format!("{column}_min")))
+                .expr_as(max_expr, Alias::new(format!("{column}_max")))
+                .and_where(SeaQExpr::col(Alias::new("file_id")).eq(file_id))
+                .and_where(SeaQExpr::col(Alias::new("row_group")).eq(row_group))
+                .and_where(SeaQExpr::col(Alias::new("column_name")).eq(column.as_str()))
+                .to_owned();
+            ctes.cte(CommonTableExpression::new().query(cte_query).table_name(cte_name.clone()).to_owned());
+
+            if i == 0 {
+                pages_query = pages_query.from(cte_name.clone()).to_owned();
+            } else {
+                pages_query = pages_query
+                    .inner_join(
+                        cte_name.clone(),
+                        SeaQExpr::col((Alias::new("page_0"), Alias::new("page_index")))
+                            .equals((cte_name.clone(), Alias::new("page_index"))),
+                    )
+                    .to_owned();
+            }
+            pages_query = pages_query
+                .column((cte_name.clone(), Alias::new(format!("{column}_min"))))
+                .column((cte_name.clone(), Alias::new(format!("{column}_max"))))
+                .to_owned();
+        }
+
+        // A referenced column's min/max can be NULL (no usable stats for that page), in which
+        // case SQL's three-valued logic would make `predicate` evaluate to NULL rather than TRUE
+        // or FALSE and a plain `WHERE predicate` would silently drop the page. OR in an explicit
+        // NULL check per referenced column, same as `get_files` does for `row_group_statistics`,
+        // so those pages are kept (i.e. "cannot prune, must scan") instead of pruned by accident.
+        let predicate = referenced_columns.iter().fold(predicate.clone(), |predicate, column| {
+            let absent = SeaQExpr::col(Alias::new(format!("{column}_min")))
+                .is_null()
+                .or(SeaQExpr::col(Alias::new(format!("{column}_max"))).is_null());
+            predicate.or(absent)
+        });
+
+        let query = pages_query
+            .and_where(predicate)
+            .with(ctes)
+            .to_owned();
+
+        let (sql, values) = self.build_sqlx(&query);
+        let qualifying_pages: Vec<(i64, i64, i64)> = sqlx::query_as_with(&sql, values)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+
+        // Check whether this row group has page statistics stored for every referenced column;
+        // if not, every row must be scanned, regardless of whether `qualifying_pages` came back
+        // empty (a column with no pages makes the inner join above return no pages at all).
+        for column in referenced_columns {
+            if !self.has_page_statistics(file_id, row_group, column).await? {
+                return Ok(None);
+            }
+        }
+
+        let mut selected_ranges: Vec<(i64, i64)> = qualifying_pages
+            .into_iter()
+            .map(|(_, first_row_index, page_row_count)| (first_row_index, first_row_index + page_row_count))
+            .collect();
+        selected_ranges.sort_unstable();
+
+        Ok(Some(RowGroupAccess::Selection(ranges_to_row_selection(
+            &selected_ranges,
+            row_group_row_count as usize,
+        ))))
+    }
+
+    async fn has_page_statistics(&self, file_id: i64, row_group: i64, column: &str) -> Result<bool> {
+        let query = Query::select()
+            .from(Alias::new("page_statistics"))
+            .expr(SeaQExpr::val(1i64))
+            .and_where(SeaQExpr::col(Alias::new("file_id")).eq(file_id))
+            .and_where(SeaQExpr::col(Alias::new("row_group")).eq(row_group))
+            .and_where(SeaQExpr::col(Alias::new("column_name")).eq(column))
+            .limit(1)
+            .to_owned();
+        let (sql, values) = self.build_sqlx(&query);
+        let row: Option<(i64,)> = sqlx::query_as_with(&sql, values)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        Ok(row.is_some())
+    }
+
+    /// `false` if some equality predicate's stored Bloom filter definitively rules the literal
+    /// out of this row group; `true` otherwise (including when a column has no stored filter,
+    /// in which case we can't say anything and must keep the row group).
+    async fn bloom_survives(&self, file_id: i64, row_group: i64, equalities: &[(String, ScalarValue)]) -> Result<bool> {
+        for (column, value) in equalities {
+            let Some(key) = bloom_key_for_scalar(value) else {
+                continue;
+            };
+
+            let query = Query::select()
+                .from(Alias::new("row_group_blooms"))
+                .column(Alias::new("bloom_filter"))
+                .and_where(SeaQExpr::col(Alias::new("file_id")).eq(file_id))
+                .and_where(SeaQExpr::col(Alias::new("row_group")).eq(row_group))
+                .and_where(SeaQExpr::col(Alias::new("column_name")).eq(column.as_str()))
+                .to_owned();
+            let (sql, values) = self.build_sqlx(&query);
+            let row: Option<(Vec<u8>,)> = sqlx::query_as_with(&sql, values)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+
+            if let Some((bits,)) = row {
+                if !bloom_might_contain(&bits, &key) {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// If this column chunk already carries a Parquet-native Split Block Bloom Filter (written by
+    /// a tool that passed `BloomFilterProperties` at write time), read it back instead of paying
+    /// to rescan every value in the row group. Returns `None` on anything that stops that from
+    /// working - no native filter present, or a read/parse failure - and the caller falls back to
+    /// building one from the column's values, same as before this existed.
+    ///
+    /// Fetches only the filter's own byte range (`store.get_range`) rather than the whole object -
+    /// this runs once per `(row_group, bloom-indexed column)` pair, so re-downloading the entire
+    /// file every time would badly outweigh the scan this is meant to avoid.
+    async fn existing_bloom_filter(
+        &self,
+        store: &Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+        row_group: &datafusion::parquet::file::metadata::RowGroupMetaData,
+        column_index: usize,
+    ) -> Option<Sbbf> {
+        let column = row_group.column(column_index);
+        let offset = column.bloom_filter_offset()?.try_into().ok()?;
+        // Parquet writers don't always record the filter's length; fall back to a generous cap
+        // rather than falling all the way back to downloading the whole object.
+        let length = column.bloom_filter_length().map_or(64 * 1024, |length| length as u64);
+        let bytes = store.get_range(path, offset..offset + length).await.ok()?;
+        Sbbf::read_from_column_chunk(column, &RangeBytes { offset, bytes }).ok().flatten()
+    }
+
+    /// Build aggregated [`Statistics`] for a set of indexed files, for use by the DataFusion
+    /// optimizer (e.g. for join-order and projection planning) instead of an unknown cardinality.
+    ///
+    /// `schema` is the schema of the table these statistics describe. Columns that aren't present
+    /// in this index (see the doc comment on [`SqlIndex::schema`]) are reported with
+    /// `Precision::Absent` rather than guessed at.
+    pub async fn statistics(&self, files: &[String], schema: &SchemaRef) -> Result<Statistics> {
+        if files.is_empty() {
+            return Ok(Statistics::new_unknown(schema));
+        }
+
+        let query = Query::select()
+            .from(Alias::new("file_statistics"))
+            .expr(SeaQExpr::col(Alias::new("row_count")).sum())
+            .and_where(SeaQExpr::col(Alias::new("file_name")).is_in(files.iter().cloned()))
+            .to_owned();
+        let (sql, values) = self.build_sqlx(&query);
+        let (row_count,): (Option<i64>,) = sqlx::query_as_with(&sql, values)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+
+        let mut column_statistics = Vec::with_capacity(schema.fields().len());
+        for field in schema.fields() {
+            // A `Struct` field itself has no `{name}_min`/`{name}_max`/etc. columns - only its
+            // leaves do, flattened under dotted/underscored names by `leaf_columns` - so there's
+            // no single row to aggregate here. Report it `Absent` like any other un-indexed
+            // column rather than querying nonexistent columns.
+            // `List`/`Map` fields likewise have no stats columns, see `leaf_columns`.
+            let is_indexed = self.schema.field_with_name(field.name()).is_ok()
+                && !matches!(
+                    field.data_type(),
+                    DataType::Struct(_)
+                        | DataType::List(_)
+                        | DataType::LargeList(_)
+                        | DataType::FixedSizeList(_, _)
+                        | DataType::Map(_, _)
+                );
+            let stats = if is_indexed {
+                self.column_statistics(field, files).await?
+            } else {
+                DFColumnStatistics::new_unknown()
+            };
+            column_statistics.push(stats);
+        }
+
+        Ok(Statistics {
+            num_rows: row_count
+                .map(|count| Precision::Exact(count as usize))
+                .unwrap_or(Precision::Absent),
+            total_byte_size: Precision::Absent,
+            column_statistics,
+        })
+    }
+
+    /// Aggregate `row_group_statistics` across `files` for a single indexed column.
+    async fn column_statistics(&self, field: &datafusion::arrow::datatypes::FieldRef, files: &[String]) -> Result<DFColumnStatistics> {
+        let column = field.name();
+
+        let query = Query::select()
+            .from(Alias::new("row_group_statistics"))
+            .inner_join(
+                Alias::new("file_statistics"),
+                SeaQExpr::col((Alias::new("row_group_statistics"), Alias::new("file_id")))
+                    .equals((Alias::new("file_statistics"), Alias::new("file_id"))),
+            )
+            .expr(SeaQExpr::cust(format!("CAST(MIN({column}_min) AS TEXT)")))
+            .expr(SeaQExpr::cust(format!("CAST(MAX({column}_max) AS TEXT)")))
+            .expr(SeaQExpr::col(Alias::new(format!("{column}_null_count"))).sum())
+            .expr(SeaQExpr::col(Alias::new(format!("{column}_distinct_count"))).max())
+            .and_where(SeaQExpr::col((Alias::new("file_statistics"), Alias::new("file_name"))).is_in(files.iter().cloned()))
+            .to_owned();
+        let (sql, values) = self.build_sqlx(&query);
+
+        let (min, max, null_count, distinct_count): (Option<String>, Option<String>, Option<i64>, Option<i64>) =
+            sqlx::query_as_with(&sql, values)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+
+        let mut stats = DFColumnStatistics::new_unknown();
+        if let Some(null_count) = null_count {
+            stats.null_count = Precision::Exact(null_count as usize);
+        }
+        if let Some(min) = min {
+            stats.min_value = Precision::Exact(scalar_from_text(&min, field.data_type()));
+        }
+        if let Some(max) = max {
+            stats.max_value = Precision::Exact(scalar_from_text(&max, field.data_type()));
+        }
+        if let Some(distinct_count) = distinct_count {
+            // The largest single row group's distinct count, not a true cross-row-group distinct
+            // count (we'd need the actual values to dedup those), so this is a lower bound at best.
+            stats.distinct_count = Precision::Inexact(distinct_count as usize);
+        }
+        Ok(stats)
+    }
+
+    /// Add a new file on the local filesystem to the index
+    ///
+    /// Reads the file through a [`LocalFileSystem`] store, the same way [`Self::add_file_from_store`]
+    /// does, except that only the file's own name (not its full path) is stored as `file_name`.
     pub async fn add_file(&mut self, file: &Path) -> anyhow::Result<()> {
         let file_name = file
             .file_name()
             .ok_or_else(|| internal_datafusion_err!("No filename"))?
             .to_str()
-            .ok_or_else(|| internal_datafusion_err!("Invalid filename"))?;
-        let file_size = file.metadata()?.len();
+            .ok_or_else(|| internal_datafusion_err!("Invalid filename"))?
+            .to_string();
+
+        let store: Arc<dyn ObjectStore> = Arc::new(LocalFileSystem::new());
+        let path = object_store::path::Path::from_filesystem_path(file)
+            .map_err(|e| DataFusionError::External(Box::new(e)).context(format!("Invalid path {file:?}")))?;
 
-        let file = File::open(file).map_err(|e| {
-            DataFusionError::from(e).context(format!("Error opening file {file:?}"))
+        self.add_object(store, path, file_name).await
+    }
+
+    /// Add a new file living in an `object_store` (S3, GCS, or any other supported store) to the
+    /// index.
+    ///
+    /// This reads the Parquet footer asynchronously via [`ParquetObjectReader`] rather than
+    /// `std::fs::File`, and takes the file size from [`object_store::ObjectMeta`] rather than
+    /// local filesystem metadata, so it works against remote storage backing a
+    /// `ListingTable`-style provider.
+    pub async fn add_file_from_store(
+        &mut self,
+        store: Arc<dyn ObjectStore>,
+        path: &object_store::path::Path,
+    ) -> anyhow::Result<()> {
+        // Store the full object-store path as `file_name` so `get_files` can hand it straight
+        // back to a provider reading from this same store.
+        let file_name = path.to_string();
+        self.add_object(store, path.clone(), file_name).await
+    }
+
+    async fn add_object(
+        &mut self,
+        store: Arc<dyn ObjectStore>,
+        path: object_store::path::Path,
+        file_name: String,
+    ) -> anyhow::Result<()> {
+        let object_meta = store.head(&path).await.map_err(|e| {
+            DataFusionError::External(Box::new(e)).context(format!("Error reading metadata for {path}"))
         })?;
+        let file_size = object_meta.size as u64;
 
-        let reader = ParquetRecordBatchReaderBuilder::try_new(file)?;
+        let reader = ParquetObjectReader::new(store.clone(), object_meta.clone());
+        // Load the page index too, so `metadata.offset_index()` below is populated when the file has one.
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder = ParquetRecordBatchStreamBuilder::new_with_options(reader, options).await?;
 
         // extract the parquet statistics from the file's footer
-        let metadata = reader.metadata();
-        let schema = reader.schema();
-        let parquet_schema = reader.parquet_schema();
+        let metadata = builder.metadata();
+        let schema = builder.schema();
+        let parquet_schema = builder.parquet_schema();
         let row_groups = metadata.row_groups();
         let row_counts = StatisticsConverter::row_group_row_counts(row_groups.iter())?;
         let mut row_group_statistics: Vec<_> = row_counts
@@ -220,146 +825,501 @@ impl SQLiteIndex {
             })
             .collect();
 
-        for field in self.schema.fields() {
-            let column_name = field.name().clone();
+        // One set of bounds per leaf - recursing into `Struct` fields, see `leaf_columns` - keyed
+        // by the leaf's real dotted Parquet path for lookups and by its underscore-joined SQL name
+        // for storage.
+        for leaf in leaf_columns(self.schema.fields()) {
+            let column_name = leaf.parquet_path.clone();
             let converter = StatisticsConverter::try_new(&column_name, schema, parquet_schema)?;
             let min_values = converter.row_group_mins(row_groups.iter())?;
             let max_values = converter.row_group_maxes(row_groups.iter())?;
             let null_counts = converter.row_group_null_counts(row_groups.iter())?;
             let null_counts = null_counts.as_primitive::<UInt64Type>();
+            // `StatisticsConverter` doesn't surface distinct counts, so pull them straight off
+            // the column chunk metadata instead; Parquet writers don't always populate this, so
+            // it's commonly absent.
+            let column_index = parquet_schema.columns().iter().position(|column| column.path().string() == column_name);
 
             for row_group in 0..metadata.num_row_groups() {
-                match field.data_type() {
+                let distinct_count = column_index.and_then(|column_index| {
+                    row_groups[row_group]
+                        .column(column_index)
+                        .statistics()
+                        .and_then(|stats| stats.distinct_count())
+                        .map(|count| count as i64)
+                });
+
+                // A `None` bound below means this row group's min/max wasn't usable - e.g. it's
+                // dictionary-only, the stats were truncated, or the encoding doesn't support them
+                // - and `get_files` must treat the row group as un-prunable rather than pretend.
+                let stats = match &leaf.data_type {
                     datafusion::arrow::datatypes::DataType::Int8 => {
                         let min_values = min_values.as_primitive::<Int8Type>();
                         let max_values = max_values.as_primitive::<Int8Type>();
-                        let min = min_values.value(row_group) as i64;
-                        let max = max_values.value(row_group) as i64;
-                        let column_statistics = ColumnStatistics {
-                            null_count: null_counts.value(row_group) as i64,
-                            stats: MinMaxStats::Int(min, max),
-                        };
-                        row_group_statistics[row_group]
-                            .column_statistics
-                            .push(column_statistics);
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Int(min_values.value(row_group) as i64, max_values.value(row_group) as i64))
                     }
                     datafusion::arrow::datatypes::DataType::UInt8 => {
                         let min_values = min_values.as_primitive::<UInt8Type>();
                         let max_values = max_values.as_primitive::<UInt8Type>();
-                        let min = min_values.value(row_group) as i64;
-                        let max = max_values.value(row_group) as i64;
-                        let column_statistics = ColumnStatistics {
-                            null_count: null_counts.value(row_group) as i64,
-                            stats: MinMaxStats::Int(min, max),
-                        };
-                        row_group_statistics[row_group]
-                            .column_statistics
-                            .push(column_statistics);
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Int(min_values.value(row_group) as i64, max_values.value(row_group) as i64))
                     }
                     datafusion::arrow::datatypes::DataType::Int16 => {
                         let min_values = min_values.as_primitive::<Int16Type>();
                         let max_values = max_values.as_primitive::<Int16Type>();
-                        let min = min_values.value(row_group) as i64;
-                        let max = max_values.value(row_group) as i64;
-                        let column_statistics = ColumnStatistics {
-                            null_count: null_counts.value(row_group) as i64,
-                            stats: MinMaxStats::Int(min, max),
-                        };
-                        row_group_statistics[row_group]
-                            .column_statistics
-                            .push(column_statistics);
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Int(min_values.value(row_group) as i64, max_values.value(row_group) as i64))
                     }
                     datafusion::arrow::datatypes::DataType::UInt16 => {
                         let min_values = min_values.as_primitive::<UInt16Type>();
                         let max_values = max_values.as_primitive::<UInt16Type>();
-                        let min = min_values.value(row_group) as i64;
-                        let max = max_values.value(row_group) as i64;
-                        let column_statistics = ColumnStatistics {
-                            null_count: null_counts.value(row_group) as i64,
-                            stats: MinMaxStats::Int(min, max),
-                        };
-                        row_group_statistics[row_group]
-                            .column_statistics
-                            .push(column_statistics);
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Int(min_values.value(row_group) as i64, max_values.value(row_group) as i64))
                     }
                     datafusion::arrow::datatypes::DataType::Int32 => {
                         let min_values = min_values.as_primitive::<Int32Type>();
                         let max_values = max_values.as_primitive::<Int32Type>();
-                        let min = min_values.value(row_group) as i64;
-                        let max = max_values.value(row_group) as i64;
-                        let column_statistics = ColumnStatistics {
-                            null_count: null_counts.value(row_group) as i64,
-                            stats: MinMaxStats::Int(min, max),
-                        };
-                        row_group_statistics[row_group]
-                            .column_statistics
-                            .push(column_statistics);
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Int(min_values.value(row_group) as i64, max_values.value(row_group) as i64))
                     }
                     datafusion::arrow::datatypes::DataType::UInt32 => {
                         let min_values = min_values.as_primitive::<UInt32Type>();
                         let max_values = max_values.as_primitive::<UInt32Type>();
-                        let min = min_values.value(row_group) as i64;
-                        let max = max_values.value(row_group) as i64;
-                        let column_statistics = ColumnStatistics {
-                            null_count: null_counts.value(row_group) as i64,
-                            stats: MinMaxStats::Int(min, max),
-                        };
-                        row_group_statistics[row_group]
-                            .column_statistics
-                            .push(column_statistics);
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Int(min_values.value(row_group) as i64, max_values.value(row_group) as i64))
                     }
                     datafusion::arrow::datatypes::DataType::Int64 => {
                         let min_values = min_values.as_primitive::<Int64Type>();
                         let max_values = max_values.as_primitive::<Int64Type>();
-                        let min = min_values.value(row_group);
-                        let max = max_values.value(row_group);
-                        let column_statistics = ColumnStatistics {
-                            null_count: null_counts.value(row_group) as i64,
-                            stats: MinMaxStats::Int(min, max),
-                        };
-                        row_group_statistics[row_group]
-                            .column_statistics
-                            .push(column_statistics);
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Int(min_values.value(row_group), max_values.value(row_group)))
                     }
                     datafusion::arrow::datatypes::DataType::Utf8 => {
                         let min_values = min_values.as_string::<i32>();
                         let max_values = max_values.as_string::<i32>();
-                        let min = min_values.value(row_group).to_string();
-                        let max = max_values.value(row_group).to_string();
-                        let column_statistics = ColumnStatistics {
-                            null_count: null_counts.value(row_group) as i64,
-                            stats: MinMaxStats::String(min, max),
-                        };
-                        row_group_statistics[row_group]
-                            .column_statistics
-                            .push(column_statistics);
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group)).then(|| {
+                            MinMaxStats::String(min_values.value(row_group).to_string(), max_values.value(row_group).to_string())
+                        })
                     }
                     datafusion::arrow::datatypes::DataType::LargeUtf8 => {
                         let min_values = min_values.as_string::<i64>();
                         let max_values = max_values.as_string::<i64>();
-                        let min = min_values.value(row_group).to_string();
-                        let max = max_values.value(row_group).to_string();
-                        let column_statistics = ColumnStatistics {
-                            null_count: null_counts.value(row_group) as i64,
-                            stats: MinMaxStats::String(min, max),
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group)).then(|| {
+                            MinMaxStats::String(min_values.value(row_group).to_string(), max_values.value(row_group).to_string())
+                        })
+                    }
+                    datafusion::arrow::datatypes::DataType::Float32 => {
+                        let min_values = min_values.as_primitive::<Float32Type>();
+                        let max_values = max_values.as_primitive::<Float32Type>();
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group)).then(|| {
+                            MinMaxStats::Float(min_values.value(row_group) as f64, max_values.value(row_group) as f64)
+                        })
+                    }
+                    datafusion::arrow::datatypes::DataType::Float64 => {
+                        let min_values = min_values.as_primitive::<Float64Type>();
+                        let max_values = max_values.as_primitive::<Float64Type>();
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Float(min_values.value(row_group), max_values.value(row_group)))
+                    }
+                    datafusion::arrow::datatypes::DataType::Boolean => {
+                        let min_values = min_values.as_boolean();
+                        let max_values = max_values.as_boolean();
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Bool(min_values.value(row_group), max_values.value(row_group)))
+                    }
+                    datafusion::arrow::datatypes::DataType::Date32 => {
+                        let min_values = min_values.as_primitive::<Date32Type>();
+                        let max_values = max_values.as_primitive::<Date32Type>();
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Int(min_values.value(row_group) as i64, max_values.value(row_group) as i64))
+                    }
+                    datafusion::arrow::datatypes::DataType::Date64 => {
+                        let min_values = min_values.as_primitive::<Date64Type>();
+                        let max_values = max_values.as_primitive::<Date64Type>();
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                            .then(|| MinMaxStats::Int(min_values.value(row_group), max_values.value(row_group)))
+                    }
+                    datafusion::arrow::datatypes::DataType::Timestamp(unit, _) => {
+                        // Stored as the raw `i64` ticks in the column's own unit: pruning compares
+                        // like-for-like against a literal that DataFusion has already normalized
+                        // to the same unit and timezone, so we don't lose precision by converting.
+                        macro_rules! timestamp_bounds {
+                            ($arrow_ty:ty) => {{
+                                let min_values = min_values.as_primitive::<$arrow_ty>();
+                                let max_values = max_values.as_primitive::<$arrow_ty>();
+                                (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                                    .then(|| MinMaxStats::Int(min_values.value(row_group), max_values.value(row_group)))
+                            }};
+                        }
+                        match unit {
+                            TimeUnit::Second => timestamp_bounds!(TimestampSecondType),
+                            TimeUnit::Millisecond => timestamp_bounds!(TimestampMillisecondType),
+                            TimeUnit::Microsecond => timestamp_bounds!(TimestampMicrosecondType),
+                            TimeUnit::Nanosecond => timestamp_bounds!(TimestampNanosecondType),
+                        }
+                    }
+                    // Same raw-ticks-in-the-column's-own-unit treatment as `Timestamp` above, just
+                    // without a timezone to preserve. `Time32` only has second/millisecond variants
+                    // and `Time64` only microsecond/nanosecond, per the Arrow spec.
+                    datafusion::arrow::datatypes::DataType::Time32(unit) => {
+                        macro_rules! time_bounds {
+                            ($arrow_ty:ty) => {{
+                                let min_values = min_values.as_primitive::<$arrow_ty>();
+                                let max_values = max_values.as_primitive::<$arrow_ty>();
+                                (!min_values.is_null(row_group) && !max_values.is_null(row_group)).then(|| {
+                                    MinMaxStats::Int(min_values.value(row_group) as i64, max_values.value(row_group) as i64)
+                                })
+                            }};
+                        }
+                        match unit {
+                            TimeUnit::Second => time_bounds!(Time32SecondType),
+                            TimeUnit::Millisecond => time_bounds!(Time32MillisecondType),
+                            TimeUnit::Microsecond | TimeUnit::Nanosecond => unreachable!("Time32 is only ever Second or Millisecond"),
+                        }
+                    }
+                    datafusion::arrow::datatypes::DataType::Time64(unit) => {
+                        macro_rules! time_bounds {
+                            ($arrow_ty:ty) => {{
+                                let min_values = min_values.as_primitive::<$arrow_ty>();
+                                let max_values = max_values.as_primitive::<$arrow_ty>();
+                                (!min_values.is_null(row_group) && !max_values.is_null(row_group))
+                                    .then(|| MinMaxStats::Int(min_values.value(row_group), max_values.value(row_group)))
+                            }};
+                        }
+                        match unit {
+                            TimeUnit::Microsecond => time_bounds!(Time64MicrosecondType),
+                            TimeUnit::Nanosecond => time_bounds!(Time64NanosecondType),
+                            TimeUnit::Second | TimeUnit::Millisecond => unreachable!("Time64 is only ever Microsecond or Nanosecond"),
+                        }
+                    }
+                    datafusion::arrow::datatypes::DataType::Decimal128(precision, scale) => {
+                        let min_values = min_values.as_primitive::<Decimal128Type>();
+                        let max_values = max_values.as_primitive::<Decimal128Type>();
+                        (!min_values.is_null(row_group) && !max_values.is_null(row_group)).then(|| {
+                            MinMaxStats::Decimal(min_values.value(row_group), max_values.value(row_group), *precision, *scale)
+                        })
+                    }
+                    // `Decimal256` (and `UInt64`, which would need to narrow into `i64` and could
+                    // silently wrap for values past `i64::MAX`) fall through to this arm and
+                    // aren't indexed at the row-group level: `MinMaxStats` can't represent their
+                    // full range without risking silently wrong bounds, which is worse than not
+                    // pruning on the column at all.
+                    //
+                    // Importantly this still produces a `ColumnStatistics` entry below (with
+                    // `stats: None`, same as an unusable min/max) rather than skipping the push -
+                    // `leaves` (used to build the DDL columns and to zip against this vec in
+                    // `add_row`) includes every leaf regardless of whether it's indexed here, and
+                    // an `Option<MinMaxStats>`-per-leaf-per-row-group list has to stay exactly that
+                    // length or `add_row`'s `leaves.iter().zip(row_group.column_statistics)` would
+                    // silently pair each later leaf with the wrong leaf's stats.
+                    _ => None,
+                };
+
+                row_group_statistics[row_group].column_statistics.push(ColumnStatistics {
+                    null_count: null_counts.value(row_group) as i64,
+                    distinct_count,
+                    stats,
+                });
+            }
+        }
+
+        // Page-level statistics, used by `get_files` to further narrow a row group down to a
+        // `RowSelection` once it has already qualified at the row-group level. Row groups without
+        // a Parquet page index (e.g. written without `enable_statistics`/page-level stats) simply
+        // get no rows here, and `get_files` falls back to scanning the whole row group.
+        let offset_index = metadata.offset_index();
+        let mut page_statistics: Vec<PageStatisticsInsert> = Vec::new();
+
+        if let Some(offset_index) = offset_index {
+            for leaf in leaf_columns(self.schema.fields()) {
+                let parquet_path = leaf.parquet_path.clone();
+                let Some(column_index) = parquet_schema
+                    .columns()
+                    .iter()
+                    .position(|column| column.path().string() == parquet_path)
+                else {
+                    continue;
+                };
+                let converter = StatisticsConverter::try_new(&parquet_path, schema, parquet_schema)?;
+
+                for row_group in 0..metadata.num_row_groups() {
+                    let Some(page_locations) = offset_index
+                        .get(row_group)
+                        .and_then(|row| row.get(column_index))
+                        .map(|offsets| &offsets.page_locations)
+                    else {
+                        continue;
+                    };
+                    if page_locations.is_empty() {
+                        continue;
+                    }
+
+                    let row_group_meta = std::iter::once(&row_groups[row_group]);
+                    let page_mins = converter.data_page_mins(row_group_meta.clone(), Some(offset_index))?;
+                    let page_maxes = converter.data_page_maxes(row_group_meta.clone(), Some(offset_index))?;
+                    let page_null_counts = converter
+                        .data_page_null_counts(row_group_meta, Some(offset_index))?
+                        .as_primitive::<UInt64Type>()
+                        .clone();
+
+                    for (page_index, page_location) in page_locations.iter().enumerate() {
+                        let null_count = page_null_counts.value(page_index) as i64;
+                        let row_count = if page_index + 1 < page_locations.len() {
+                            page_locations[page_index + 1].first_row_index - page_location.first_row_index
+                        } else {
+                            row_counts[row_group].unwrap() - page_location.first_row_index
+                        };
+
+                        // A `None` bound below means this page's min/max wasn't usable (same
+                        // causes as the row-group level above), so `page_level_access` must treat
+                        // the page as un-prunable rather than scan it with a made-up bound.
+                        let stats = match &leaf.data_type {
+                            datafusion::arrow::datatypes::DataType::Int8 => {
+                                let page_mins = page_mins.as_primitive::<Int8Type>();
+                                let page_maxes = page_maxes.as_primitive::<Int8Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Int(page_mins.value(page_index) as i64, page_maxes.value(page_index) as i64))
+                            }
+                            datafusion::arrow::datatypes::DataType::UInt8 => {
+                                let page_mins = page_mins.as_primitive::<UInt8Type>();
+                                let page_maxes = page_maxes.as_primitive::<UInt8Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Int(page_mins.value(page_index) as i64, page_maxes.value(page_index) as i64))
+                            }
+                            datafusion::arrow::datatypes::DataType::Int16 => {
+                                let page_mins = page_mins.as_primitive::<Int16Type>();
+                                let page_maxes = page_maxes.as_primitive::<Int16Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Int(page_mins.value(page_index) as i64, page_maxes.value(page_index) as i64))
+                            }
+                            datafusion::arrow::datatypes::DataType::UInt16 => {
+                                let page_mins = page_mins.as_primitive::<UInt16Type>();
+                                let page_maxes = page_maxes.as_primitive::<UInt16Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Int(page_mins.value(page_index) as i64, page_maxes.value(page_index) as i64))
+                            }
+                            datafusion::arrow::datatypes::DataType::Int32 => {
+                                let page_mins = page_mins.as_primitive::<Int32Type>();
+                                let page_maxes = page_maxes.as_primitive::<Int32Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Int(page_mins.value(page_index) as i64, page_maxes.value(page_index) as i64))
+                            }
+                            datafusion::arrow::datatypes::DataType::UInt32 => {
+                                let page_mins = page_mins.as_primitive::<UInt32Type>();
+                                let page_maxes = page_maxes.as_primitive::<UInt32Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Int(page_mins.value(page_index) as i64, page_maxes.value(page_index) as i64))
+                            }
+                            datafusion::arrow::datatypes::DataType::Int64 => {
+                                let page_mins = page_mins.as_primitive::<Int64Type>();
+                                let page_maxes = page_maxes.as_primitive::<Int64Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Int(page_mins.value(page_index), page_maxes.value(page_index)))
+                            }
+                            datafusion::arrow::datatypes::DataType::Utf8 => {
+                                let page_mins = page_mins.as_string::<i32>();
+                                let page_maxes = page_maxes.as_string::<i32>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index)).then(|| {
+                                    MinMaxStats::String(page_mins.value(page_index).to_string(), page_maxes.value(page_index).to_string())
+                                })
+                            }
+                            datafusion::arrow::datatypes::DataType::LargeUtf8 => {
+                                let page_mins = page_mins.as_string::<i64>();
+                                let page_maxes = page_maxes.as_string::<i64>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index)).then(|| {
+                                    MinMaxStats::String(page_mins.value(page_index).to_string(), page_maxes.value(page_index).to_string())
+                                })
+                            }
+                            datafusion::arrow::datatypes::DataType::Float32 => {
+                                let page_mins = page_mins.as_primitive::<Float32Type>();
+                                let page_maxes = page_maxes.as_primitive::<Float32Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index)).then(|| {
+                                    MinMaxStats::Float(page_mins.value(page_index) as f64, page_maxes.value(page_index) as f64)
+                                })
+                            }
+                            datafusion::arrow::datatypes::DataType::Float64 => {
+                                let page_mins = page_mins.as_primitive::<Float64Type>();
+                                let page_maxes = page_maxes.as_primitive::<Float64Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Float(page_mins.value(page_index), page_maxes.value(page_index)))
+                            }
+                            datafusion::arrow::datatypes::DataType::Boolean => {
+                                let page_mins = page_mins.as_boolean();
+                                let page_maxes = page_maxes.as_boolean();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Bool(page_mins.value(page_index), page_maxes.value(page_index)))
+                            }
+                            datafusion::arrow::datatypes::DataType::Date32 => {
+                                let page_mins = page_mins.as_primitive::<Date32Type>();
+                                let page_maxes = page_maxes.as_primitive::<Date32Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Int(page_mins.value(page_index) as i64, page_maxes.value(page_index) as i64))
+                            }
+                            datafusion::arrow::datatypes::DataType::Date64 => {
+                                let page_mins = page_mins.as_primitive::<Date64Type>();
+                                let page_maxes = page_maxes.as_primitive::<Date64Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                    .then(|| MinMaxStats::Int(page_mins.value(page_index), page_maxes.value(page_index)))
+                            }
+                            datafusion::arrow::datatypes::DataType::Timestamp(unit, _) => {
+                                macro_rules! timestamp_bounds {
+                                    ($arrow_ty:ty) => {{
+                                        let page_mins = page_mins.as_primitive::<$arrow_ty>();
+                                        let page_maxes = page_maxes.as_primitive::<$arrow_ty>();
+                                        (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                            .then(|| MinMaxStats::Int(page_mins.value(page_index), page_maxes.value(page_index)))
+                                    }};
+                                }
+                                match unit {
+                                    TimeUnit::Second => timestamp_bounds!(TimestampSecondType),
+                                    TimeUnit::Millisecond => timestamp_bounds!(TimestampMillisecondType),
+                                    TimeUnit::Microsecond => timestamp_bounds!(TimestampMicrosecondType),
+                                    TimeUnit::Nanosecond => timestamp_bounds!(TimestampNanosecondType),
+                                }
+                            }
+                            datafusion::arrow::datatypes::DataType::Time32(unit) => {
+                                macro_rules! time_bounds {
+                                    ($arrow_ty:ty) => {{
+                                        let page_mins = page_mins.as_primitive::<$arrow_ty>();
+                                        let page_maxes = page_maxes.as_primitive::<$arrow_ty>();
+                                        (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index)).then(|| {
+                                            MinMaxStats::Int(page_mins.value(page_index) as i64, page_maxes.value(page_index) as i64)
+                                        })
+                                    }};
+                                }
+                                match unit {
+                                    TimeUnit::Second => time_bounds!(Time32SecondType),
+                                    TimeUnit::Millisecond => time_bounds!(Time32MillisecondType),
+                                    TimeUnit::Microsecond | TimeUnit::Nanosecond => unreachable!("Time32 is only ever Second or Millisecond"),
+                                }
+                            }
+                            datafusion::arrow::datatypes::DataType::Time64(unit) => {
+                                macro_rules! time_bounds {
+                                    ($arrow_ty:ty) => {{
+                                        let page_mins = page_mins.as_primitive::<$arrow_ty>();
+                                        let page_maxes = page_maxes.as_primitive::<$arrow_ty>();
+                                        (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index))
+                                            .then(|| MinMaxStats::Int(page_mins.value(page_index), page_maxes.value(page_index)))
+                                    }};
+                                }
+                                match unit {
+                                    TimeUnit::Microsecond => time_bounds!(Time64MicrosecondType),
+                                    TimeUnit::Nanosecond => time_bounds!(Time64NanosecondType),
+                                    TimeUnit::Second | TimeUnit::Millisecond => unreachable!("Time64 is only ever Microsecond or Nanosecond"),
+                                }
+                            }
+                            datafusion::arrow::datatypes::DataType::Decimal128(precision, scale) => {
+                                let page_mins = page_mins.as_primitive::<Decimal128Type>();
+                                let page_maxes = page_maxes.as_primitive::<Decimal128Type>();
+                                (!page_mins.is_null(page_index) && !page_maxes.is_null(page_index)).then(|| {
+                                    MinMaxStats::Decimal(page_mins.value(page_index), page_maxes.value(page_index), *precision, *scale)
+                                })
+                            }
+                            _ => continue, // not indexed at the row-group level either, see above
                         };
-                        row_group_statistics[row_group]
-                            .column_statistics
-                            .push(column_statistics);
+
+                        page_statistics.push(PageStatisticsInsert {
+                            row_group: row_group as i64,
+                            column_name: leaf.sql_name.clone(),
+                            page_index: page_index as i64,
+                            first_row_index: page_location.first_row_index,
+                            row_count,
+                            null_count,
+                            stats,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Bloom filters for the columns flagged via `with_bloom_filter_columns`, one per row
+        // group, for high-cardinality columns where min/max pruning rarely helps (e.g. a
+        // synthetic `id`) - that's why it's opt-in rather than something we build for every
+        // column. Stored as a real Parquet Split Block Bloom Filter ([`Sbbf`]) bitset so
+        // `bloom_might_contain` can reuse the same battle-tested block/salt layout Parquet itself
+        // uses, rather than a bespoke hash scheme.
+        //
+        // If the column chunk already carries its own Parquet-native bloom filter (written by a
+        // tool that passed `BloomFilterProperties` at write time), we read it back via
+        // `Sbbf::read_from_column_chunk` and reuse it as-is instead of paying to rescan the
+        // column's values. Most files won't have one (it's an opt-in Parquet writer feature), in
+        // which case we fall back to building one from the column's values exactly as before.
+        let bloom_fields: Vec<_> = self
+            .schema
+            .fields()
+            .iter()
+            .filter(|field| self.bloom_columns.contains(field.name()))
+            .collect();
+        let mut bloom_filters: Vec<BloomFilterInsert> = Vec::new();
+
+        if !bloom_fields.is_empty() {
+            let projection =
+                ProjectionMask::columns(parquet_schema, bloom_fields.iter().map(|field| field.name().as_str()));
+
+            for row_group in 0..metadata.num_row_groups() {
+                let mut reused = Vec::with_capacity(bloom_fields.len());
+                let mut bits: Vec<Sbbf> = Vec::with_capacity(bloom_fields.len());
+                for field in &bloom_fields {
+                    let column_index = parquet_schema.columns().iter().position(|column| column.name() == field.name());
+                    let existing = match column_index {
+                        Some(column_index) => {
+                            self.existing_bloom_filter(&store, &path, &row_groups[row_group], column_index).await
+                        }
+                        None => None,
+                    };
+                    reused.push(existing.is_some());
+                    bits.push(existing.unwrap_or_else(|| {
+                        Sbbf::new_with_ndv_fpp(row_counts[row_group].unwrap_or(0).max(1) as u64, 0.01)
+                            .unwrap_or_else(|_| Sbbf::new_with_ndv_fpp(1, 0.01).expect("1 ndv is always valid"))
+                    }));
+                }
+
+                // Only columns without a reusable filter need their values actually scanned.
+                if reused.iter().any(|&is_reused| !is_reused) {
+                    let reader = ParquetObjectReader::new(store.clone(), object_meta.clone());
+                    let mut stream = ParquetRecordBatchStreamBuilder::new(reader)
+                        .await?
+                        .with_row_groups(vec![row_group])
+                        .with_projection(projection.clone())
+                        .build()?;
+
+                    while let Some(batch) = stream.try_next().await? {
+                        for (i, field) in bloom_fields.iter().enumerate() {
+                            if !reused[i] {
+                                bloom_insert_array(&mut bits[i], batch.column(i), field.data_type());
+                            }
+                        }
                     }
-                    _ => {} // ignore other types, we just don't put them in the index and filters will not be pushed down
+                }
+
+                for (field, bits) in bloom_fields.iter().zip(bits) {
+                    let mut serialized = Vec::new();
+                    bits.write_bitset(&mut serialized)?;
+                    bloom_filters.push(BloomFilterInsert {
+                        row_group: row_group as i64,
+                        column_name: field.name().clone(),
+                        bits: serialized,
+                    });
                 }
             }
         }
 
+        let partitions = self
+            .partition_schema
+            .as_ref()
+            .map(|partition_schema| parse_hive_partitions(&path, partition_schema))
+            .unwrap_or_default();
+
         let file_statistics = FileStatisticsInsert {
             file_name: file_name.to_string(),
             file_size_bytes: file_size as i64,
             row_group_count: metadata.num_row_groups() as i64,
             row_count: metadata.file_metadata().num_rows(),
+            partitions,
         };
 
-        self.add_row(file_statistics, row_group_statistics).await?;
+        self.add_row(file_statistics, row_group_statistics, page_statistics, bloom_filters).await?;
         Ok(())
     }
 
@@ -367,12 +1327,14 @@ impl SQLiteIndex {
         &self,
         file_statistics: FileStatisticsInsert,
         row_group_statistics: Vec<RowGroupStatisticsInsert>,
+        page_statistics: Vec<PageStatisticsInsert>,
+        bloom_filters: Vec<BloomFilterInsert>,
     ) -> anyhow::Result<()> {
         self.initialize().await?;
 
         let mut transaction = self.pool.begin().await?;
 
-        let (sql, values) = Query::insert()
+        let query = Query::insert()
             .into_table(Alias::new("file_statistics"))
             .columns(vec![
                 Alias::new("file_name"),
@@ -396,16 +1358,18 @@ impl SQLiteIndex {
                     .to_owned(),
             )
             .returning(Query::returning().column(Alias::new("file_id")))
-            .build_sqlx(SqliteQueryBuilder);
+            .to_owned();
+        let (sql, values) = self.build_sqlx(&query);
         let (file_id,): (i64,) = sqlx::query_as_with(&sql, values)
             .fetch_one(&mut *transaction)
             .await?;
 
         // Delete any existing column statistics for this file
-        let (sql, values) = Query::delete()
+        let query = Query::delete()
             .from_table(Alias::new("row_group_statistics"))
             .and_where(SeaQExpr::col(Alias::new("file_id")).eq(file_id))
-            .build_sqlx(SqliteQueryBuilder);
+            .to_owned();
+        let (sql, values) = self.build_sqlx(&query);
         sqlx::query_with(&sql, values)
             .execute(&mut *transaction)
             .await?;
@@ -416,10 +1380,12 @@ impl SQLiteIndex {
             Alias::new("row_count"),
         ];
 
-        for field in self.schema.fields() {
-            columns.push(Alias::new(format!("{}_null_count", field.name())));
-            columns.push(Alias::new(format!("{}_min", field.name())));
-            columns.push(Alias::new(format!("{}_max", field.name())));
+        let leaves = leaf_columns(self.schema.fields());
+        for leaf in &leaves {
+            columns.push(Alias::new(format!("{}_null_count", leaf.sql_name)));
+            columns.push(Alias::new(format!("{}_distinct_count", leaf.sql_name)));
+            columns.push(Alias::new(format!("{}_min", leaf.sql_name)));
+            columns.push(Alias::new(format!("{}_max", leaf.sql_name)));
         }
 
         let mut query = Query::insert()
@@ -433,30 +1399,215 @@ impl SQLiteIndex {
                 statistics.row_group.into(),
                 statistics.row_count.into(),
             ];
-            for stats in statistics.column_statistics {
+            for (leaf, stats) in leaves.iter().zip(statistics.column_statistics) {
+                values.push(stats.null_count.into());
+                values.push(stats.distinct_count.into());
                 match stats.stats {
-                    MinMaxStats::Int(min, max) => {
-                        values.push(stats.null_count.into());
+                    Some(MinMaxStats::Int(min, max)) => {
                         values.push(min.into());
                         values.push(max.into());
                     }
-                    MinMaxStats::String(min, max) => {
-                        values.push(stats.null_count.into());
+                    Some(MinMaxStats::String(min, max)) => {
                         values.push(min.into());
                         values.push(max.into());
                     }
+                    Some(MinMaxStats::Float(min, max)) => {
+                        values.push(min.into());
+                        values.push(max.into());
+                    }
+                    Some(MinMaxStats::Bool(min, max)) => {
+                        values.push(min.into());
+                        values.push(max.into());
+                    }
+                    Some(MinMaxStats::Decimal(min, max, _precision, _scale)) => {
+                        values.push(min.to_string().into());
+                        values.push(max.to_string().into());
+                    }
+                    None => push_null_min_max(&mut values, &leaf.data_type),
                 }
             }
 
             query = query.values_panic(values).to_owned();
         }
 
-        let (sql, values) = query.build_sqlx(SqliteQueryBuilder);
+        let (sql, values) = self.build_sqlx(&query);
 
         sqlx::query_with(&sql, values)
             .execute(&mut *transaction)
             .await?;
 
+        // Delete any existing page statistics for this file
+        let query = Query::delete()
+            .from_table(Alias::new("page_statistics"))
+            .and_where(SeaQExpr::col(Alias::new("file_id")).eq(file_id))
+            .to_owned();
+        let (sql, values) = self.build_sqlx(&query);
+        sqlx::query_with(&sql, values)
+            .execute(&mut *transaction)
+            .await?;
+
+        if !page_statistics.is_empty() {
+            let mut query = Query::insert()
+                .into_table(Alias::new("page_statistics"))
+                .columns(vec![
+                    Alias::new("file_id"),
+                    Alias::new("row_group"),
+                    Alias::new("column_name"),
+                    Alias::new("page_index"),
+                    Alias::new("first_row_index"),
+                    Alias::new("row_count"),
+                    Alias::new("null_count"),
+                    Alias::new("int_min"),
+                    Alias::new("int_max"),
+                    Alias::new("string_min"),
+                    Alias::new("string_max"),
+                    Alias::new("float_min"),
+                    Alias::new("float_max"),
+                    Alias::new("bool_min"),
+                    Alias::new("bool_max"),
+                    Alias::new("decimal_min"),
+                    Alias::new("decimal_max"),
+                ])
+                .to_owned();
+
+            for page in page_statistics {
+                let (int_min, int_max, string_min, string_max, float_min, float_max, bool_min, bool_max, decimal_min, decimal_max) =
+                    match page.stats {
+                        Some(MinMaxStats::Int(min, max)) => (Some(min), Some(max), None, None, None, None, None, None, None, None),
+                        Some(MinMaxStats::String(min, max)) => (None, None, Some(min), Some(max), None, None, None, None, None, None),
+                        Some(MinMaxStats::Float(min, max)) => (None, None, None, None, Some(min), Some(max), None, None, None, None),
+                        Some(MinMaxStats::Bool(min, max)) => (None, None, None, None, None, None, Some(min), Some(max), None, None),
+                        Some(MinMaxStats::Decimal(min, max, _, _)) => {
+                            (None, None, None, None, None, None, None, None, Some(min.to_string()), Some(max.to_string()))
+                        }
+                        // No usable stats for this page - every field stays NULL, and
+                        // `page_level_access`'s per-column `IS NULL` fallback keeps it unprunable.
+                        None => (None, None, None, None, None, None, None, None, None, None),
+                    };
+
+                query = query
+                    .values_panic(vec![
+                        file_id.into(),
+                        page.row_group.into(),
+                        page.column_name.into(),
+                        page.page_index.into(),
+                        page.first_row_index.into(),
+                        page.row_count.into(),
+                        page.null_count.into(),
+                        int_min.into(),
+                        int_max.into(),
+                        string_min.into(),
+                        string_max.into(),
+                        float_min.into(),
+                        float_max.into(),
+                        bool_min.into(),
+                        bool_max.into(),
+                        decimal_min.into(),
+                        decimal_max.into(),
+                    ])
+                    .to_owned();
+            }
+
+            let (sql, values) = self.build_sqlx(&query);
+            sqlx::query_with(&sql, values)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        // Delete any existing bloom filters for this file
+        let query = Query::delete()
+            .from_table(Alias::new("row_group_blooms"))
+            .and_where(SeaQExpr::col(Alias::new("file_id")).eq(file_id))
+            .to_owned();
+        let (sql, values) = self.build_sqlx(&query);
+        sqlx::query_with(&sql, values)
+            .execute(&mut *transaction)
+            .await?;
+
+        if !bloom_filters.is_empty() {
+            let mut query = Query::insert()
+                .into_table(Alias::new("row_group_blooms"))
+                .columns(vec![
+                    Alias::new("file_id"),
+                    Alias::new("row_group"),
+                    Alias::new("column_name"),
+                    Alias::new("bloom_filter"),
+                ])
+                .to_owned();
+
+            for bloom in bloom_filters {
+                query = query
+                    .values_panic(vec![
+                        file_id.into(),
+                        bloom.row_group.into(),
+                        bloom.column_name.into(),
+                        bloom.bits.into(),
+                    ])
+                    .to_owned();
+            }
+
+            let (sql, values) = self.build_sqlx(&query);
+            sqlx::query_with(&sql, values)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        // Delete any existing partition values for this file
+        let query = Query::delete()
+            .from_table(Alias::new("file_partitions"))
+            .and_where(SeaQExpr::col(Alias::new("file_id")).eq(file_id))
+            .to_owned();
+        let (sql, values) = self.build_sqlx(&query);
+        sqlx::query_with(&sql, values)
+            .execute(&mut *transaction)
+            .await?;
+
+        if !file_statistics.partitions.is_empty() {
+            let mut query = Query::insert()
+                .into_table(Alias::new("file_partitions"))
+                .columns(vec![
+                    Alias::new("file_id"),
+                    Alias::new("partition_column"),
+                    Alias::new("int_value"),
+                    Alias::new("string_value"),
+                    Alias::new("float_value"),
+                    Alias::new("bool_value"),
+                    Alias::new("decimal_value"),
+                ])
+                .to_owned();
+
+            for (column, value) in &file_statistics.partitions {
+                let (int_value, string_value, float_value, bool_value, decimal_value) = match scalar_to_single_value(value) {
+                    Some(MinMaxStats::Int(v, _)) => (Some(v), None, None, None, None),
+                    Some(MinMaxStats::String(v, _)) => (None, Some(v), None, None, None),
+                    Some(MinMaxStats::Float(v, _)) => (None, None, Some(v), None, None),
+                    Some(MinMaxStats::Bool(v, _)) => (None, None, None, Some(v), None),
+                    Some(MinMaxStats::Decimal(v, _, _, _)) => (None, None, None, None, Some(v.to_string())),
+                    // Not indexable (e.g. `NULL`): leave every typed column absent so the
+                    // partition column still resolves (via the join) as NULL, and phase 1's
+                    // `IS NULL` fallback keeps the file rather than pruning it on this column.
+                    None => (None, None, None, None, None),
+                };
+
+                query = query
+                    .values_panic(vec![
+                        file_id.into(),
+                        column.clone().into(),
+                        int_value.into(),
+                        string_value.into(),
+                        float_value.into(),
+                        bool_value.into(),
+                        decimal_value.into(),
+                    ])
+                    .to_owned();
+            }
+
+            let (sql, values) = self.build_sqlx(&query);
+            sqlx::query_with(&sql, values)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
         transaction.commit().await?;
 
         Ok(())
@@ -464,32 +1615,11 @@ impl SQLiteIndex {
 
     /// Simple migration function that idempotently creates the table for the index
     pub async fn initialize(&self) -> anyhow::Result<()> {
-        let query = r#"
-            CREATE TABLE IF NOT EXISTS file_statistics (
-                file_id INTEGER PRIMARY KEY AUTOINCREMENT,
-                file_name TEXT NOT NULL UNIQUE,
-                file_size_bytes INTEGER NOT NULL,
-                row_group_count INTEGER NOT NULL,
-                row_count INTEGER NOT NULL
-            )
-        "#;
-        sqlx::query(&query).execute(&self.pool).await?;
-
         // The statistics columns are hardcoded in this example
         // It would be up to you to decide if this is appropriate for your use case
         // You could also store the statistics in a more flexible way, e.g. as a JSON blob or as an entity-attribute-value table
-        // let query = r#"
-        //     CREATE TABLE IF NOT EXISTS row_group_statistics (
-        //         file_id INTEGER NOT NULL,
-        //         row_group INTEGER NOT NULL,
-        //         row_count INTEGER NOT NULL,
-        //         PRIMARY KEY (file_id, row_group),
-        //         FOREIGN KEY (file_id) REFERENCES file_statistics(file_id)
-        //     )
-        // "#;
-        // sqlx::query(&query).execute(&self.pool).await?;
-
-        let sql = Table::create()
+
+        let table = Table::create()
             .table(Alias::new("file_statistics"))
             .if_not_exists()
             .col(ColumnDef::new(Alias::new("file_id")).integer().primary_key().auto_increment())
@@ -497,8 +1627,8 @@ impl SQLiteIndex {
             .col(ColumnDef::new(Alias::new("file_size_bytes")).integer().not_null())
             .col(ColumnDef::new(Alias::new("row_group_count")).integer().not_null())
             .col(ColumnDef::new(Alias::new("row_count")).integer().not_null())
-            .to_owned()
-            .build(SqliteQueryBuilder);
+            .to_owned();
+        let sql = self.build_schema(&table);
 
         sqlx::query(&sql).execute(&self.pool).await?;
 
@@ -517,31 +1647,476 @@ impl SQLiteIndex {
             )
             .to_owned();
 
-        for field in self.schema.fields().iter() {
+        // One set of `_null_count`/`_distinct_count`/`_min`/`_max` columns per leaf - recursing
+        // into `Struct` fields and keying the columns by their underscore-joined path (e.g. `address_zip_min`)
+        // so nested fields get pruned just like top-level ones.
+        for leaf in leaf_columns(self.schema.fields()) {
             table.col(
-                ColumnDef::new(Alias::new(format!("{}_null_count", field.name())))
+                ColumnDef::new(Alias::new(format!("{}_null_count", leaf.sql_name)))
                 .integer()
                 .not_null()
             );
+            // Nullable: Parquet writers don't always populate a column chunk's distinct count.
+            table.col(ColumnDef::new(Alias::new(format!("{}_distinct_count", leaf.sql_name))).big_integer());
             for suffix in ["min", "max"] {
-                let mut stats_col = ColumnDef::new(Alias::new(format!("{}_{}", field.name(), suffix)));
-                set_column_type(&mut stats_col, field.data_type().clone());
-                if !field.is_nullable() {
-                    stats_col.not_null();
-                }
+                // Always nullable, regardless of whether the source field is: real Parquet files
+                // frequently omit min/max for a row group (dictionary-only, truncated stats,
+                // unsupported encodings) even when the column itself can't contain nulls.
+                let mut stats_col = ColumnDef::new(Alias::new(format!("{}_{}", leaf.sql_name, suffix)));
+                set_column_type(&mut stats_col, leaf.data_type.clone(), self.backend);
                 table.col(&mut stats_col);
             }
         }
 
-        let sql = table.build(SqliteQueryBuilder);
+        let sql = self.build_schema(&table);
 
         sqlx::query(&sql).execute(&self.pool).await?;
 
+        // Page-level statistics, one row per data page. Unlike `row_group_statistics` this is an
+        // entity-attribute-value table keyed by `column_name`, because different columns can have
+        // a different number of pages per row group.
+        let page_statistics = Table::create()
+            .table(Alias::new("page_statistics"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("file_id")).integer().not_null())
+            .col(ColumnDef::new(Alias::new("row_group")).integer().not_null())
+            .col(ColumnDef::new(Alias::new("column_name")).string().not_null())
+            .col(ColumnDef::new(Alias::new("page_index")).integer().not_null())
+            .col(ColumnDef::new(Alias::new("first_row_index")).big_integer().not_null())
+            .col(ColumnDef::new(Alias::new("row_count")).big_integer().not_null())
+            .col(ColumnDef::new(Alias::new("null_count")).big_integer().not_null())
+            .col(ColumnDef::new(Alias::new("int_min")).big_integer())
+            .col(ColumnDef::new(Alias::new("int_max")).big_integer())
+            .col(ColumnDef::new(Alias::new("string_min")).string())
+            .col(ColumnDef::new(Alias::new("string_max")).string())
+            .col(ColumnDef::new(Alias::new("float_min")).double())
+            .col(ColumnDef::new(Alias::new("float_max")).double())
+            .col(ColumnDef::new(Alias::new("bool_min")).boolean())
+            .col(ColumnDef::new(Alias::new("bool_max")).boolean())
+            // Stored as text to avoid losing precision on SQLite's 64-bit integers, see `MinMaxStats::Decimal`.
+            .col(ColumnDef::new(Alias::new("decimal_min")).string())
+            .col(ColumnDef::new(Alias::new("decimal_max")).string())
+            .primary_key(
+                Index::create()
+                    .col(Alias::new("file_id"))
+                    .col(Alias::new("row_group"))
+                    .col(Alias::new("column_name"))
+                    .col(Alias::new("page_index")),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(Alias::new("page_statistics"), Alias::new("file_id"))
+                    .to(Alias::new("file_statistics"), Alias::new("file_id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        let page_statistics = self.build_schema(&page_statistics);
+
+        sqlx::query(&page_statistics).execute(&self.pool).await?;
+
+        // Per-(file, row group, column) Split Block Bloom Filters, for the columns named in
+        // `bloom_columns`. Absence of a row here just means that column/row-group wasn't
+        // bloom-indexed, not that the row group necessarily contains no matching rows.
+        let row_group_blooms = Table::create()
+            .table(Alias::new("row_group_blooms"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("file_id")).integer().not_null())
+            .col(ColumnDef::new(Alias::new("row_group")).integer().not_null())
+            .col(ColumnDef::new(Alias::new("column_name")).string().not_null())
+            .col(ColumnDef::new(Alias::new("bloom_filter")).binary().not_null())
+            .primary_key(
+                Index::create()
+                    .col(Alias::new("file_id"))
+                    .col(Alias::new("row_group"))
+                    .col(Alias::new("column_name")),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(Alias::new("row_group_blooms"), Alias::new("file_id"))
+                    .to(Alias::new("file_statistics"), Alias::new("file_id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        let row_group_blooms = self.build_schema(&row_group_blooms);
+
+        sqlx::query(&row_group_blooms).execute(&self.pool).await?;
+
+        // One row per (file, partition column), for Hive-partitioned tables - see
+        // `Self::with_partition_schema`. Left empty when the table isn't partitioned. Reuses the
+        // same typed `int`/`string`/`float`/`bool`/`decimal` column family as `page_statistics`
+        // (via `scalar_to_single_value`) rather than a single untyped value column, so comparisons
+        // against a partition column use the right SQL type instead of a string compare.
+        let file_partitions = Table::create()
+            .table(Alias::new("file_partitions"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("file_id")).integer().not_null())
+            .col(ColumnDef::new(Alias::new("partition_column")).string().not_null())
+            .col(ColumnDef::new(Alias::new("int_value")).big_integer())
+            .col(ColumnDef::new(Alias::new("string_value")).string())
+            .col(ColumnDef::new(Alias::new("float_value")).double())
+            .col(ColumnDef::new(Alias::new("bool_value")).boolean())
+            .col(ColumnDef::new(Alias::new("decimal_value")).string())
+            .primary_key(Index::create().col(Alias::new("file_id")).col(Alias::new("partition_column")))
+            .foreign_key(
+                ForeignKey::create()
+                    .from(Alias::new("file_partitions"), Alias::new("file_id"))
+                    .to(Alias::new("file_statistics"), Alias::new("file_id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        let file_partitions = self.build_schema(&file_partitions);
+
+        sqlx::query(&file_partitions).execute(&self.pool).await?;
+
         Ok(())
     }
 }
 
-fn set_column_type(column: &mut ColumnDef, field_type: DataType) -> &mut ColumnDef {
+/// Break a filter into its top-level `AND`-conjuncts, e.g. `year = 2024 AND price > 100` becomes
+/// `[year = 2024, price > 100]`. A filter with no top-level `AND` becomes a single conjunct.
+/// Lets callers like [`SqlIndex::files_surviving_partition_pruning`] keep only the conjuncts that
+/// are actually valid in a restricted query scope, instead of rejecting (or mis-scoping) the
+/// whole filter just because part of it references something out of scope.
+fn split_conjuncts(expr: &Arc<dyn PhysicalExpr>, out: &mut Vec<Arc<dyn PhysicalExpr>>) {
+    if let Some(binary) = expr.as_any().downcast_ref::<phys_expr::BinaryExpr>() {
+        if *binary.op() == Operator::And {
+            split_conjuncts(binary.left(), out);
+            split_conjuncts(binary.right(), out);
+            return;
+        }
+    }
+    out.push(Arc::clone(expr));
+}
+
+/// Collect the set of column names a raw (not yet `PruningPredicate`-rewritten) filter
+/// references, e.g. `year = 2024 AND region = 'us'` contributes `["year", "region"]`. Used to
+/// find which of the filter's columns are Hive partition columns, where we want the literal
+/// column name rather than `referenced_min_max_columns`'s `_min`/`_max` stripping.
+fn referenced_columns(expr: &Arc<dyn PhysicalExpr>) -> Vec<String> {
+    let mut columns = Vec::new();
+    expr.apply(|e| {
+        if let Some(column) = e.as_any().downcast_ref::<phys_expr::Column>() {
+            if !columns.iter().any(|c: &String| c == column.name()) {
+                columns.push(column.name().to_string());
+            }
+        }
+        Ok(datafusion_common::tree_node::TreeNodeRecursion::Continue)
+    })
+    .expect("column matching never fails");
+    columns
+}
+
+/// Collect the set of base column names a pruning predicate references, e.g. `a_min <= 5` and
+/// `a_max >= 5` both contribute `"a"`.
+fn referenced_min_max_columns(expr: &Arc<dyn PhysicalExpr>) -> Vec<String> {
+    let mut columns = Vec::new();
+    expr.apply(|e| {
+        if let Some(column) = e.as_any().downcast_ref::<phys_expr::Column>() {
+            let base = column
+                .name()
+                .strip_suffix("_min")
+                .or_else(|| column.name().strip_suffix("_max"));
+            if let Some(base) = base {
+                if !columns.iter().any(|c: &String| c == base) {
+                    columns.push(base.to_string());
+                }
+            }
+        }
+        Ok(datafusion_common::tree_node::TreeNodeRecursion::Continue)
+    })
+    .expect("column matching never fails");
+    columns
+}
+
+/// Pull out `column = literal` constraints referencing a bloom-indexed column, so `get_files`
+/// can use `row_group_blooms` to prune row groups that min/max statistics can't help with
+/// (e.g. an equality lookup on a high-cardinality column spread across its whole range).
+fn bloom_equality_predicates(
+    expr: &Arc<dyn PhysicalExpr>,
+    bloom_columns: &std::collections::HashSet<String>,
+) -> Vec<(String, ScalarValue)> {
+    // Only top-level `AND`-conjuncts constrain every matching row; an equality under an `OR` or
+    // `NOT` doesn't, so ruling its literal out of a row group says nothing about the filter.
+    let mut conjuncts = Vec::new();
+    split_conjuncts(expr, &mut conjuncts);
+    let mut equalities = Vec::new();
+    for conjunct in &conjuncts {
+        if let Some(binary) = conjunct.as_any().downcast_ref::<phys_expr::BinaryExpr>() {
+            if *binary.op() == Operator::Eq {
+                let found = [(binary.left(), binary.right()), (binary.right(), binary.left())]
+                    .into_iter()
+                    .find_map(|(maybe_column, maybe_literal)| {
+                        let column = maybe_column.as_any().downcast_ref::<phys_expr::Column>()?;
+                        let literal = maybe_literal.as_any().downcast_ref::<phys_expr::Literal>()?;
+                        Some((column.name().to_string(), literal.value().clone()))
+                    });
+                if let Some((column, value)) = found {
+                    if bloom_columns.contains(&column) {
+                        equalities.push((column, value));
+                    }
+                }
+            }
+        }
+    }
+    equalities
+}
+
+/// A `get_range`-fetched slice of a Parquet file, addressed with the *file's* absolute byte
+/// offsets rather than offsets relative to the slice - [`Sbbf::read_from_column_chunk`] looks up
+/// the filter using [`ColumnChunkMetaData::bloom_filter_offset`], which is a whole-file offset, so
+/// a plain `Bytes` (which [`ChunkReader`] indexes from its own start) can't stand in for a range
+/// fetched from partway through the file without this translation.
+struct RangeBytes {
+    offset: u64,
+    bytes: bytes::Bytes,
+}
+
+impl datafusion::parquet::file::reader::Length for RangeBytes {
+    fn len(&self) -> u64 {
+        self.offset + self.bytes.len() as u64
+    }
+}
+
+impl datafusion::parquet::file::reader::ChunkReader for RangeBytes {
+    type T = bytes::buf::Reader<bytes::Bytes>;
+
+    fn get_read(&self, start: u64) -> datafusion::parquet::errors::Result<Self::T> {
+        let length = self.bytes.len() as u64 - (start - self.offset);
+        Ok(self.get_bytes(start, length as usize)?.reader())
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> datafusion::parquet::errors::Result<bytes::Bytes> {
+        let local = (start - self.offset) as usize;
+        Ok(self.bytes.slice(local..local + length))
+    }
+}
+
+/// `false` is definitive (the filter was never told about `value`); `true` only means "maybe",
+/// at whatever false positive rate the filter was sized for. `bits` is a serialized [`Sbbf`]
+/// bitset, the same Split Block Bloom Filter layout Parquet itself uses for column chunk bloom
+/// filters (see [`SqlIndex::existing_bloom_filter`]).
+fn bloom_might_contain(bits: &[u8], value: &[u8]) -> bool {
+    if bits.is_empty() {
+        return true;
+    }
+    Sbbf::new(bits).check(value)
+}
+
+/// Insert every value of `array` into `bits`, using the same byte encoding per type as
+/// [`bloom_key_for_scalar`] so a literal looked up later checks against the same positions.
+fn bloom_insert_array(bits: &mut Sbbf, array: &datafusion::arrow::array::ArrayRef, data_type: &DataType) {
+    match data_type {
+        DataType::Int8
+        | DataType::UInt8
+        | DataType::Int16
+        | DataType::UInt16
+        | DataType::Int32
+        | DataType::UInt32
+        | DataType::Int64
+        | DataType::UInt64
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(_)
+        | DataType::Time64(_) => {
+            if let Ok(values) = datafusion::arrow::compute::cast(array, &DataType::Int64) {
+                for value in values.as_primitive::<Int64Type>().iter().flatten() {
+                    bits.insert(value.to_le_bytes().as_slice());
+                }
+            }
+        }
+        DataType::Float32 | DataType::Float64 => {
+            if let Ok(values) = datafusion::arrow::compute::cast(array, &DataType::Float64) {
+                for value in values.as_primitive::<Float64Type>().iter().flatten() {
+                    bits.insert(value.to_le_bytes().as_slice());
+                }
+            }
+        }
+        DataType::Utf8 => {
+            for value in array.as_string::<i32>().iter().flatten() {
+                bits.insert(value.as_bytes());
+            }
+        }
+        DataType::LargeUtf8 => {
+            for value in array.as_string::<i64>().iter().flatten() {
+                bits.insert(value.as_bytes());
+            }
+        }
+        DataType::Boolean => {
+            for value in array.as_boolean().iter().flatten() {
+                bits.insert([value as u8].as_slice());
+            }
+        }
+        _ => {} // not bloom-indexable, same type coverage as row-group/page statistics
+    }
+}
+
+/// The same byte encoding [`bloom_insert_array`] used when building the filter, so a literal
+/// pulled out of a pushed-down equality predicate hashes to the same bit positions. Returns
+/// `None` for scalar variants we don't bloom-index (including `NULL`, which can never equal
+/// anything), in which case the caller should treat the column as un-prunable for this literal.
+fn bloom_key_for_scalar(value: &ScalarValue) -> Option<Vec<u8>> {
+    match value {
+        ScalarValue::Int8(Some(v)) => Some((*v as i64).to_le_bytes().to_vec()),
+        ScalarValue::UInt8(Some(v)) => Some((*v as i64).to_le_bytes().to_vec()),
+        ScalarValue::Int16(Some(v)) => Some((*v as i64).to_le_bytes().to_vec()),
+        ScalarValue::UInt16(Some(v)) => Some((*v as i64).to_le_bytes().to_vec()),
+        ScalarValue::Int32(Some(v)) => Some((*v as i64).to_le_bytes().to_vec()),
+        ScalarValue::UInt32(Some(v)) => Some((*v as i64).to_le_bytes().to_vec()),
+        ScalarValue::Int64(Some(v)) => Some(v.to_le_bytes().to_vec()),
+        // Values past `i64::MAX` became nulls in `bloom_insert_array`'s cast and were never inserted.
+        ScalarValue::UInt64(Some(v)) => i64::try_from(*v).ok().map(|v| v.to_le_bytes().to_vec()),
+        ScalarValue::Date32(Some(v)) => Some((*v as i64).to_le_bytes().to_vec()),
+        ScalarValue::Date64(Some(v)) => Some(v.to_le_bytes().to_vec()),
+        ScalarValue::TimestampSecond(Some(v), _)
+        | ScalarValue::TimestampMillisecond(Some(v), _)
+        | ScalarValue::TimestampMicrosecond(Some(v), _)
+        | ScalarValue::TimestampNanosecond(Some(v), _) => Some(v.to_le_bytes().to_vec()),
+        ScalarValue::Time32Second(Some(v)) | ScalarValue::Time32Millisecond(Some(v)) => Some((*v as i64).to_le_bytes().to_vec()),
+        ScalarValue::Time64Microsecond(Some(v)) | ScalarValue::Time64Nanosecond(Some(v)) => Some(v.to_le_bytes().to_vec()),
+        ScalarValue::Float32(Some(v)) => Some((*v as f64).to_le_bytes().to_vec()),
+        ScalarValue::Float64(Some(v)) => Some(v.to_le_bytes().to_vec()),
+        ScalarValue::Utf8(Some(v)) | ScalarValue::LargeUtf8(Some(v)) => Some(v.as_bytes().to_vec()),
+        ScalarValue::Boolean(Some(v)) => Some(vec![*v as u8]),
+        _ => None,
+    }
+}
+
+/// Turn a parsed partition value into the same typed `int`/`string`/`float`/`bool`/`decimal`
+/// column family `row_group_statistics`/`page_statistics` already use for min/max bounds, reusing
+/// [`MinMaxStats`] with an equal min/max to represent the single value. Returns `None` for scalar
+/// variants we don't index (mirrors the row-group/page-level stats coverage).
+fn scalar_to_single_value(value: &ScalarValue) -> Option<MinMaxStats> {
+    match value {
+        ScalarValue::Int8(Some(v)) => Some(MinMaxStats::Int(*v as i64, *v as i64)),
+        ScalarValue::UInt8(Some(v)) => Some(MinMaxStats::Int(*v as i64, *v as i64)),
+        ScalarValue::Int16(Some(v)) => Some(MinMaxStats::Int(*v as i64, *v as i64)),
+        ScalarValue::UInt16(Some(v)) => Some(MinMaxStats::Int(*v as i64, *v as i64)),
+        ScalarValue::Int32(Some(v)) => Some(MinMaxStats::Int(*v as i64, *v as i64)),
+        ScalarValue::UInt32(Some(v)) => Some(MinMaxStats::Int(*v as i64, *v as i64)),
+        ScalarValue::Int64(Some(v)) => Some(MinMaxStats::Int(*v, *v)),
+        // `UInt64` falls through to the `_` arm below, same as (and for the same reason as) the
+        // row-group/page-level stats match arms: narrowing into `i64` can silently wrap for
+        // values past `i64::MAX`, which would make a partition value compare as negative and
+        // silently corrupt phase-1 pruning instead of just not pruning on it.
+        ScalarValue::Date32(Some(v)) => Some(MinMaxStats::Int(*v as i64, *v as i64)),
+        ScalarValue::Date64(Some(v)) => Some(MinMaxStats::Int(*v, *v)),
+        ScalarValue::TimestampSecond(Some(v), _)
+        | ScalarValue::TimestampMillisecond(Some(v), _)
+        | ScalarValue::TimestampMicrosecond(Some(v), _)
+        | ScalarValue::TimestampNanosecond(Some(v), _) => Some(MinMaxStats::Int(*v, *v)),
+        ScalarValue::Time32Second(Some(v)) | ScalarValue::Time32Millisecond(Some(v)) => {
+            Some(MinMaxStats::Int(*v as i64, *v as i64))
+        }
+        ScalarValue::Time64Microsecond(Some(v)) | ScalarValue::Time64Nanosecond(Some(v)) => Some(MinMaxStats::Int(*v, *v)),
+        ScalarValue::Float32(Some(v)) => Some(MinMaxStats::Float(*v as f64, *v as f64)),
+        ScalarValue::Float64(Some(v)) => Some(MinMaxStats::Float(*v, *v)),
+        ScalarValue::Utf8(Some(v)) | ScalarValue::LargeUtf8(Some(v)) => Some(MinMaxStats::String(v.clone(), v.clone())),
+        ScalarValue::Boolean(Some(v)) => Some(MinMaxStats::Bool(*v, *v)),
+        ScalarValue::Decimal128(Some(v), precision, scale) => Some(MinMaxStats::Decimal(*v, *v, *precision, *scale)),
+        _ => None,
+    }
+}
+
+/// Turn a sorted set of qualifying `[start, end)` row ranges within a row group into a
+/// `RowSelection` that selects exactly those rows and skips everything else, merging adjacent
+/// ranges so the resulting selector count stays minimal.
+fn ranges_to_row_selection(selected_ranges: &[(i64, i64)], row_group_row_count: usize) -> RowSelection {
+    let mut selectors = Vec::new();
+    let mut cursor = 0usize;
+
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for &(start, end) in selected_ranges {
+        let (start, end) = (start as usize, end as usize);
+        match merged.last_mut() {
+            Some((_, last_end)) if *last_end == start => *last_end = end,
+            _ => merged.push((start, end)),
+        }
+    }
+
+    for (start, end) in merged {
+        if start > cursor {
+            selectors.push(RowSelector::skip(start - cursor));
+        }
+        selectors.push(RowSelector::select(end - start));
+        cursor = end;
+    }
+    if cursor < row_group_row_count {
+        selectors.push(RowSelector::skip(row_group_row_count - cursor));
+    }
+
+    RowSelection::from(selectors)
+}
+
+/// Parse a stats value we stored as SQLite TEXT (via `CAST(... AS TEXT)`) back into a
+/// `ScalarValue` of the column's real arrow type, so the optimizer sees typed bounds rather than
+/// strings.
+fn scalar_from_text(text: &str, data_type: &DataType) -> ScalarValue {
+    match data_type {
+        DataType::Int8 => ScalarValue::Int8(text.parse().ok()),
+        DataType::UInt8 => ScalarValue::UInt8(text.parse().ok()),
+        DataType::Int16 => ScalarValue::Int16(text.parse().ok()),
+        DataType::UInt16 => ScalarValue::UInt16(text.parse().ok()),
+        DataType::Int32 => ScalarValue::Int32(text.parse().ok()),
+        DataType::UInt32 => ScalarValue::UInt32(text.parse().ok()),
+        DataType::Int64 => ScalarValue::Int64(text.parse().ok()),
+        DataType::UInt64 => ScalarValue::UInt64(text.parse().ok()),
+        DataType::Float32 => ScalarValue::Float32(text.parse().ok()),
+        DataType::Float64 => ScalarValue::Float64(text.parse().ok()),
+        DataType::Utf8 => ScalarValue::Utf8(Some(text.to_string())),
+        DataType::LargeUtf8 => ScalarValue::LargeUtf8(Some(text.to_string())),
+        DataType::Boolean => ScalarValue::Boolean(Some(text == "1" || text.eq_ignore_ascii_case("true"))),
+        DataType::Date32 => ScalarValue::Date32(text.parse().ok()),
+        DataType::Date64 => ScalarValue::Date64(text.parse().ok()),
+        DataType::Timestamp(TimeUnit::Second, tz) => ScalarValue::TimestampSecond(text.parse().ok(), tz.clone()),
+        DataType::Timestamp(TimeUnit::Millisecond, tz) => ScalarValue::TimestampMillisecond(text.parse().ok(), tz.clone()),
+        DataType::Timestamp(TimeUnit::Microsecond, tz) => ScalarValue::TimestampMicrosecond(text.parse().ok(), tz.clone()),
+        DataType::Timestamp(TimeUnit::Nanosecond, tz) => ScalarValue::TimestampNanosecond(text.parse().ok(), tz.clone()),
+        DataType::Time32(TimeUnit::Second) => ScalarValue::Time32Second(text.parse().ok()),
+        DataType::Time32(TimeUnit::Millisecond) => ScalarValue::Time32Millisecond(text.parse().ok()),
+        DataType::Time64(TimeUnit::Microsecond) => ScalarValue::Time64Microsecond(text.parse().ok()),
+        DataType::Time64(TimeUnit::Nanosecond) => ScalarValue::Time64Nanosecond(text.parse().ok()),
+        DataType::Decimal128(precision, scale) => {
+            // We always write this column the raw unscaled `i128` (see the `MinMaxStats::Decimal`
+            // arm in `add_row`), but on Postgres the `_min`/`_max` columns are a native `numeric`
+            // (see `set_column_type`), so `CAST(... AS TEXT)` renders it with Postgres's own
+            // scale-driven decimal point (e.g. "12345.00") instead of the plain integer text
+            // SQLite/MySQL give back. That fractional part is always zero by construction, so
+            // truncate at the first `.` before parsing rather than letting it fail `i128::parse`.
+            let integral = text.split('.').next().unwrap_or(text);
+            ScalarValue::Decimal128(integral.parse().ok(), *precision, *scale)
+        }
+        other => ScalarValue::try_from(other).unwrap_or(ScalarValue::Null),
+    }
+}
+
+/// Push a pair of typed SQL `NULL`s for a row group's absent min/max, matching whichever
+/// `MinMaxStats` variant this field's data would otherwise be stored as (see the match in
+/// `add_object`), so the column keeps a consistent type affinity whether or not stats are present.
+fn push_null_min_max(values: &mut Vec<SimpleExpr>, data_type: &DataType) {
+    match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            values.push(Option::<String>::None.into());
+            values.push(Option::<String>::None.into());
+        }
+        DataType::Float32 | DataType::Float64 => {
+            values.push(Option::<f64>::None.into());
+            values.push(Option::<f64>::None.into());
+        }
+        DataType::Boolean => {
+            values.push(Option::<bool>::None.into());
+            values.push(Option::<bool>::None.into());
+        }
+        // Everything else we index (Int8/UInt8/.../Int64, Date32/Date64, Timestamp) is stored as
+        // `MinMaxStats::Int`, i.e. a plain `i64`.
+        _ => {
+            values.push(Option::<i64>::None.into());
+            values.push(Option::<i64>::None.into());
+        }
+    }
+}
+
+fn set_column_type(column: &mut ColumnDef, field_type: DataType, backend: Backend) -> &mut ColumnDef {
     match field_type {
         DataType::Int8 => column.tiny_integer(),
         DataType::UInt8 => column.tiny_unsigned(),
@@ -558,6 +2133,23 @@ fn set_column_type(column: &mut ColumnDef, field_type: DataType) -> &mut ColumnD
         DataType::Binary => column.binary(),
         DataType::FixedSizeBinary(_) => column.binary(),
         DataType::LargeBinary => column.binary(),
+        DataType::Boolean => column.boolean(),
+        // Stored as the number of days/ms/the underlying timestamp unit since the epoch (since
+        // midnight for `Time32`/`Time64`) as an `i64`, see `MinMaxStats::Int`, regardless of the
+        // width of the source type.
+        DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(_)
+        | DataType::Time64(_) => column.big_integer(),
+        // Postgres has a native arbitrary-precision `numeric` type, so there we store decimals
+        // as `.decimal()` directly. SQLite and MySQL have no 128-bit integer type, so elsewhere we
+        // fall back to the base-10 text representation of the raw unscaled `i128` to avoid
+        // precision loss.
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => match backend {
+            Backend::Postgres => column.decimal(),
+            Backend::Sqlite | Backend::MySql => column.string(),
+        },
         _ => todo!("Add support for more types"),
     }
 }
@@ -568,10 +2160,103 @@ pub struct FileScanPlan {
     pub access_plan: ParquetAccessPlan,
 }
 
+/// A single indexable (primitive, non-nested) column reachable from the table's schema, after
+/// recursively flattening any `Struct` fields into a dotted path (e.g. a `zip` field nested inside
+/// an `address` struct becomes `address.zip`).
+struct LeafColumn {
+    /// The SQL identifier this leaf's `_min`/`_max`/`_null_count`/`_distinct_count` columns and
+    /// `page_statistics`/`row_group_blooms` `column_name` rows are keyed by. Dots aren't valid in
+    /// unquoted SQL identifiers, so `path` is joined with `_` here (e.g. `address_zip`).
+    sql_name: String,
+    /// The real dotted path (e.g. `address.zip`) used to look this leaf up in the file's
+    /// Arrow/Parquet schema via [`StatisticsConverter::try_new`] and `parquet_schema.columns()`.
+    parquet_path: String,
+    data_type: DataType,
+}
+
+/// Recursively walk `fields`, emitting one [`LeafColumn`] per primitive leaf and descending into
+/// any `Struct` fields. Lists and maps aren't flattened - Parquet doesn't store simple per-leaf
+/// min/max for repeated fields the way it does for scalar leaves - so columns under a List/Map
+/// are simply left out of the index, the same as any other unsupported type.
+fn leaf_columns(fields: &Fields) -> Vec<LeafColumn> {
+    fn walk(fields: &Fields, prefix: &str, out: &mut Vec<LeafColumn>) {
+        for field in fields {
+            let path = if prefix.is_empty() { field.name().clone() } else { format!("{prefix}.{}", field.name()) };
+            match field.data_type() {
+                DataType::Struct(children) => walk(children, &path, out),
+                DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _) | DataType::Map(_, _) => {}
+                data_type => out.push(LeafColumn {
+                    sql_name: path.replace('.', "_"),
+                    parquet_path: path,
+                    data_type: data_type.clone(),
+                }),
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(fields, "", &mut out);
+    out
+}
+
+/// Parse Hive-style `key=value` directory segments out of an object-store path (e.g.
+/// `year=2024/region=us/data.parquet`), keeping only the segments named in `partition_schema` and
+/// parsing each value as that column's Arrow type via [`scalar_from_text`]. Segments that aren't a
+/// `key=value` pair, or whose key isn't a declared partition column, are ignored.
+fn parse_hive_partitions(
+    path: &object_store::path::Path,
+    partition_schema: &SchemaRef,
+) -> Vec<(String, ScalarValue)> {
+    path.to_string()
+        .split('/')
+        .filter_map(|segment| segment.split_once('='))
+        .filter_map(|(key, value)| {
+            let field = partition_schema.field_with_name(key).ok()?;
+            Some((key.to_string(), scalar_from_text(value, field.data_type())))
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub enum MinMaxStats {
     Int(i64, i64),
     String(String, String),
+    Float(f64, f64),
+    Bool(bool, bool),
+    /// Raw `i128` min/max plus the `(precision, scale)` of the decimal column they came from, so
+    /// a literal can be rescaled to match before comparison.
+    Decimal(i128, i128, u8, u8),
+}
+
+/// The typed-column family (`int`/`string`/`float`/`bool`/`decimal`) that `page_statistics` and
+/// `file_partitions` store a value of this Arrow type under, mirroring [`MinMaxStats`]'s variants
+/// and the row-group-level stats match arms above. `None` for types neither table indexes
+/// (`UInt64`, `Decimal256`, etc - same exclusions as row-group/page-level stats, see
+/// `scalar_to_single_value`).
+///
+/// Knowing this statically from the column's declared type lets callers select the one typed
+/// column that's actually populated for it, instead of `COALESCE`ing every typed column together -
+/// which only "works" under SQLite's dynamic typing and is a hard type-mismatch error on Postgres.
+fn min_max_column_kind(data_type: &DataType) -> Option<&'static str> {
+    match data_type {
+        DataType::Int8
+        | DataType::UInt8
+        | DataType::Int16
+        | DataType::UInt16
+        | DataType::Int32
+        | DataType::UInt32
+        | DataType::Int64
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(_)
+        | DataType::Time64(_) => Some("int"),
+        DataType::Utf8 | DataType::LargeUtf8 => Some("string"),
+        DataType::Float32 | DataType::Float64 => Some("float"),
+        DataType::Boolean => Some("bool"),
+        DataType::Decimal128(_, _) => Some("decimal"),
+        _ => None,
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -595,7 +2280,11 @@ impl RowGroupStatisticsInsert {
 #[derive(Debug, Clone)]
 pub struct ColumnStatistics {
     null_count: i64,
-    stats: MinMaxStats,
+    /// From the Parquet column chunk's own statistics, when the writer populated it.
+    distinct_count: Option<i64>,
+    /// `None` when the column chunk has no usable min/max (dictionary-only, truncated stats,
+    /// or an unsupported encoding). Pruning must treat this as "cannot prune, must scan".
+    stats: Option<MinMaxStats>,
 }
 
 #[derive(Debug, Clone)]
@@ -604,4 +2293,30 @@ struct FileStatisticsInsert {
     file_size_bytes: i64,
     row_group_count: i64,
     row_count: i64,
+    /// Parsed `key=value` Hive partition segments from the file's path, typed against
+    /// [`SqlIndex::partition_schema`]. Empty when the index has no declared partition schema.
+    partitions: Vec<(String, ScalarValue)>,
+}
+
+/// A single row group's worth of per-page statistics for one column
+#[derive(Debug, Clone)]
+struct PageStatisticsInsert {
+    row_group: i64,
+    column_name: String,
+    page_index: i64,
+    first_row_index: i64,
+    row_count: i64,
+    null_count: i64,
+    /// `None` when this page has no usable min/max (dictionary-only, truncated stats, etc.) -
+    /// `page_level_access` must then treat the page as un-prunable, same as a `None` row-group
+    /// bound.
+    stats: Option<MinMaxStats>,
+}
+
+/// A single row group's Bloom filter bit array for one bloom-indexed column.
+#[derive(Debug, Clone)]
+struct BloomFilterInsert {
+    row_group: i64,
+    column_name: String,
+    bits: Vec<u8>,
 }
\ No newline at end of file